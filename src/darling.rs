@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+#[derive(Default, Debug, PartialEq)]
+pub struct DarlingDennison {
+    /// map of pairs of modes (i, j) in 2ωᵢ = 2ωⱼ resonance to the
+    /// corresponding Darling-Dennison constant, K_iijj, in cm⁻¹, if SPECTRO
+    /// reported one
+    pub data: HashMap<(usize, usize), Option<f64>>,
+}
+
+/// simpler representation for almost trivially implementing Serialize and
+/// Deserialize
+#[derive(Deserialize)]
+struct DummyDarling {
+    modes: Vec<(usize, usize)>,
+    constants: Vec<Option<f64>>,
+}
+
+impl From<DummyDarling> for DarlingDennison {
+    fn from(value: DummyDarling) -> Self {
+        Self {
+            data: value.modes.into_iter().zip(value.constants).collect(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DarlingDennison {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = DummyDarling::deserialize(deserializer)?;
+        Ok(s.into())
+    }
+}
+
+impl Serialize for DarlingDennison {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("DarlingDennison", 2)?;
+        let mut modes = Vec::new();
+        let mut constants = Vec::new();
+        let mut keys: Vec<_> = self.data.keys().collect();
+        keys.sort();
+        for key in keys {
+            modes.push(key);
+            constants.push(self.data[key]);
+        }
+        s.serialize_field("modes", &modes)?;
+        s.serialize_field("constants", &constants)?;
+        s.end()
+    }
+}
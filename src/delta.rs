@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::reduction::Representation;
+
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Delta {
     /// these constants are in the A-reduced Watson Hamiltonian
@@ -41,6 +43,65 @@ impl Delta {
     }
 }
 
+impl Delta {
+    /// report whether `self` contains any nonzero A-reduced constants. the
+    /// output from my spectro includes zeros for constants it did not compute
+    pub fn has_a(&self) -> bool {
+        [
+            self.big_delta_j,
+            self.big_delta_k,
+            self.big_delta_jk,
+            self.delta_j,
+            self.delta_k,
+        ]
+        .iter()
+        .flatten()
+        .any(|&d| d != 0.0)
+    }
+
+    /// report whether `self` contains any nonzero S-reduced constants
+    pub fn has_s(&self) -> bool {
+        [self.d_j, self.d_jk, self.d_k, self.d1, self.d2]
+            .iter()
+            .flatten()
+            .any(|&d| d != 0.0)
+    }
+
+    /// compute the S-reduced constants from the A-reduced constants in `self`
+    /// and the rotational constants `rots` in representation `rep`. only the
+    /// S-reduced fields of the result are set. returns `None` if any of the
+    /// A-reduced constants are missing or `rots` is not an asymmetric top
+    pub fn a_to_s(&self, rots: &[f64], rep: Representation) -> Option<Self> {
+        let (sigma, _) = rep.sigma(rots)?;
+        let d2 = -self.delta_k? / (4.0 * sigma);
+        Some(Self {
+            d_j: Some(self.big_delta_j? + 2.0 * d2),
+            d_jk: Some(self.big_delta_jk? - 12.0 * d2),
+            d_k: Some(self.big_delta_k? + 10.0 * d2),
+            d1: Some(-self.delta_j?),
+            d2: Some(d2),
+            ..Default::default()
+        })
+    }
+
+    /// compute the A-reduced constants from the S-reduced constants in `self`
+    /// and the rotational constants `rots` in representation `rep`. only the
+    /// A-reduced fields of the result are set. returns `None` if any of the
+    /// S-reduced constants are missing or `rots` is not an asymmetric top
+    pub fn s_to_a(&self, rots: &[f64], rep: Representation) -> Option<Self> {
+        let (sigma, _) = rep.sigma(rots)?;
+        let d2 = self.d2?;
+        Some(Self {
+            big_delta_j: Some(self.d_j? - 2.0 * d2),
+            big_delta_k: Some(self.d_k? - 10.0 * d2),
+            big_delta_jk: Some(self.d_jk? + 12.0 * d2),
+            delta_j: Some(-self.d1?),
+            delta_k: Some(-4.0 * sigma * d2),
+            ..Default::default()
+        })
+    }
+}
+
 impl Display for Delta {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write_dist_consts!(f, self,
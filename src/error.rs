@@ -0,0 +1,88 @@
+use std::{fmt::Display, path::PathBuf};
+
+use crate::State;
+
+/// an error encountered while constructing a [crate::Summary] from a file
+#[derive(Debug)]
+pub enum ParseError {
+    /// the path given was a directory instead of a file
+    IsDir(PathBuf),
+
+    /// failed to open or read the file
+    Io {
+        file: PathBuf,
+        /// line number, starting at 1, if the error occurred while reading
+        line: Option<usize>,
+        err: std::io::Error,
+    },
+
+    /// failed to deserialize a JSON output file from Rust spectro
+    Json {
+        file: PathBuf,
+        err: serde_json::Error,
+    },
+
+    /// failed to parse a line of a SPECTRO output file
+    Line {
+        file: PathBuf,
+        /// line number, starting at 1
+        line: usize,
+        /// the state the parser was in when it encountered the line
+        state: State,
+        /// the offending line
+        text: String,
+        /// description of what went wrong
+        msg: String,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::IsDir(file) => {
+                write!(f, "{} is a directory", file.display())
+            }
+            ParseError::Io {
+                file,
+                line: None,
+                err,
+            } => {
+                write!(f, "failed to read {} with '{err}'", file.display())
+            }
+            ParseError::Io {
+                file,
+                line: Some(line),
+                err,
+            } => write!(
+                f,
+                "failed to read {}:{line} with '{err}'",
+                file.display()
+            ),
+            ParseError::Json { file, err } => {
+                write!(f, "failed to parse {} with '{err}'", file.display())
+            }
+            ParseError::Line {
+                file,
+                line,
+                state,
+                text,
+                msg,
+            } => write!(
+                f,
+                "{}:{line}: {msg} in state {state:?}: '{}'",
+                file.display(),
+                text.trim_end()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io { err, .. } => Some(err),
+            ParseError::Json { err, .. } => Some(err),
+            ParseError::IsDir(_) | ParseError::Line { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+/// sparse quadratic, cubic, and quartic force constants in the internal
+/// coordinates printed by SPECTRO
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ForceConstants {
+    /// map of coordinate indices to force constants. the indices are SPECTRO's
+    /// 1-based internal coordinate numbers in the descending order it prints
+    /// them, so a quadratic constant has a key like `[2, 1]` and a quartic
+    /// constant a key like `[3, 3, 2, 1]`. these are the non-redundant
+    /// internal or symmetry coordinates SPECTRO transforms the force field
+    /// into, not [crate::Summary::curvils], which can contain more
+    /// coordinates than there are vibrational modes. the values are `None`
+    /// for constants too large for SPECTRO to print
+    pub data: HashMap<Vec<usize>, Option<f64>>,
+}
+
+impl ForceConstants {
+    /// return the force constant for the coordinate indices in `key`, in any
+    /// order. the outer `Option` indicates whether the constant was present at
+    /// all
+    pub fn get(&self, key: &[usize]) -> Option<Option<f64>> {
+        let mut key = key.to_vec();
+        key.sort_unstable_by(|a, b| b.cmp(a));
+        self.data.get(&key).copied()
+    }
+
+    /// insert `v` into `self` with `key` sorted into SPECTRO's order
+    pub(crate) fn insert(&mut self, mut key: Vec<usize>, v: Option<f64>) {
+        key.sort_unstable_by(|a, b| b.cmp(a));
+        self.data.insert(key, v);
+    }
+
+    /// return the keys of `self`, sorted first by the order of the derivative
+    /// and then by the indices themselves
+    pub fn keys(&self) -> Vec<&Vec<usize>> {
+        let mut keys: Vec<_> = self.data.keys().collect();
+        keys.sort_unstable_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+        keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// simpler representation for almost trivially implementing Serialize and
+/// Deserialize
+#[derive(Deserialize)]
+struct DummyFc {
+    coords: Vec<Vec<usize>>,
+    values: Vec<Option<f64>>,
+}
+
+impl From<DummyFc> for ForceConstants {
+    fn from(value: DummyFc) -> Self {
+        Self {
+            data: value.coords.into_iter().zip(value.values).collect(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ForceConstants {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = DummyFc::deserialize(deserializer)?;
+        Ok(s.into())
+    }
+}
+
+impl Serialize for ForceConstants {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("ForceConstants", 2)?;
+        let mut coords = Vec::new();
+        let mut values = Vec::new();
+        for key in self.keys() {
+            coords.push(key);
+            values.push(self.data[key]);
+        }
+        s.serialize_field("coords", &coords)?;
+        s.serialize_field("values", &values)?;
+        s.end()
+    }
+}
@@ -0,0 +1,139 @@
+use std::iter::zip;
+
+use symm::Molecule;
+
+/// h / 8π², for converting rotational constants in MHz to moments of inertia
+/// in amu Å²
+pub const INERTIA_CONV: f64 = 505379.009;
+
+/// compute the inertia defect Δ = Ic − Ia − Ib in amu Å² from the rotational
+/// constants `rots` in MHz. returns `None` unless `rots` contains the three
+/// constants of an asymmetric top
+pub fn inertia_defect(rots: &[f64]) -> Option<f64> {
+    let [a, b, c] = rots else {
+        return None;
+    };
+    let mut v = [*a, *b, *c];
+    v.sort_by(|a, b| b.total_cmp(a));
+    let [ia, ib, ic] = v.map(|r| INERTIA_CONV / r);
+    Some(ic - ia - ib)
+}
+
+/// parse a state label from the PERTURBED VIBRATIONAL INERTIA DEFECT section,
+/// like `v  1`, `2v  3`, or `v 5 + v 6`, into the quanta in each mode. the
+/// result is padded with zeros to at least `nmodes` modes
+pub(crate) fn parse_label(
+    label: &str,
+    nmodes: usize,
+) -> Result<Vec<usize>, String> {
+    let label: String = label.split_whitespace().collect();
+    let mut ret = vec![0; nmodes];
+    for term in label.split('+') {
+        let Some((q, mode)) = term.split_once('v') else {
+            return Err(format!("failed to parse state '{term}'"));
+        };
+        let q = if q.is_empty() {
+            1
+        } else {
+            q.parse()
+                .map_err(|_| format!("invalid quanta in '{term}'"))?
+        };
+        let mode: usize = mode
+            .parse()
+            .map_err(|_| format!("invalid mode in '{term}'"))?;
+        if mode == 0 {
+            return Err(format!("invalid mode in '{term}'"));
+        }
+        if mode > ret.len() {
+            ret.resize(mode, 0);
+        }
+        ret[mode - 1] += q;
+    }
+    Ok(ret)
+}
+
+/// masses in amu of the most abundant isotope of each element, indexed by
+/// atomic number, for geometries that don't come with their own weights
+const MASSES: [f64; 23] = [
+    0.0, 1.0078250, 4.0026032, 7.0160030, 9.0121822, 11.0093054, 12.0000000,
+    14.0030740, 15.9949146, 18.9984032, 19.9924356, 22.9897677, 23.9850423,
+    26.9815386, 27.9769271, 30.9737620, 31.9720707, 34.9688527, 39.9623837,
+    38.9637064, 39.9625906, 44.9559119, 47.9479473,
+];
+
+/// return the mass in amu of the most abundant isotope of the element with
+/// `atomic_number`, if it is known
+pub fn atomic_mass(atomic_number: usize) -> Option<f64> {
+    MASSES.get(atomic_number).copied().filter(|&m| m > 0.0)
+}
+
+/// compute the inertia tensor in amu Å² of the atoms in `geom` with the
+/// corresponding `masses` in amu, about their center of mass
+pub fn inertia_tensor(geom: &Molecule, masses: &[f64]) -> [[f64; 3]; 3] {
+    let atoms: Vec<_> = zip(masses, &geom.atoms)
+        .map(|(&m, a)| (m, [a.x, a.y, a.z]))
+        .collect();
+    let total: f64 = atoms.iter().map(|(m, _)| m).sum();
+    if total == 0.0 {
+        return [[0.0; 3]; 3];
+    }
+    let mut com = [0.0; 3];
+    for (m, r) in &atoms {
+        for (c, x) in com.iter_mut().zip(r) {
+            *c += m * x / total;
+        }
+    }
+    let mut mat = [[0.0; 3]; 3];
+    for (m, r) in &atoms {
+        let r = [r[0] - com[0], r[1] - com[1], r[2] - com[2]];
+        let r2: f64 = r.iter().map(|x| x * x).sum();
+        for (i, row) in mat.iter_mut().enumerate() {
+            for (j, v) in row.iter_mut().enumerate() {
+                let d = if i == j { r2 } else { 0.0 };
+                *v += m * (d - r[i] * r[j]);
+            }
+        }
+    }
+    mat
+}
+
+/// compute the principal moments of inertia in amu Å² of the atoms in `geom`
+/// with the corresponding `masses` in amu. the moments are returned in
+/// ascending order
+pub fn principal_moments(geom: &Molecule, masses: &[f64]) -> [f64; 3] {
+    sym_eigenvalues(inertia_tensor(geom, masses))
+}
+
+/// convert the principal moments of inertia in `moments`, in amu Å², to the
+/// rotational constants A, B, and C in MHz, in descending order. a vanishing
+/// moment, like Ia for a linear molecule, gives an infinite constant
+pub fn rot_consts(moments: [f64; 3]) -> [f64; 3] {
+    let mut ret = moments.map(|i| INERTIA_CONV / i);
+    ret.sort_by(|a, b| b.total_cmp(a));
+    ret
+}
+
+/// compute the eigenvalues of the symmetric 3x3 matrix `mat` in ascending
+/// order, using the trigonometric solution of the characteristic polynomial
+fn sym_eigenvalues(mat: [[f64; 3]; 3]) -> [f64; 3] {
+    let p1 = mat[0][1].powi(2) + mat[0][2].powi(2) + mat[1][2].powi(2);
+    let mut ret = if p1 == 0.0 {
+        [mat[0][0], mat[1][1], mat[2][2]]
+    } else {
+        let q = (mat[0][0] + mat[1][1] + mat[2][2]) / 3.0;
+        let p2 =
+            (0..3).map(|i| (mat[i][i] - q).powi(2)).sum::<f64>() + 2.0 * p1;
+        let p = (p2 / 6.0).sqrt();
+        let b =
+            |i: usize, j: usize| (mat[i][j] - if i == j { q } else { 0.0 }) / p;
+        let det = b(0, 0) * (b(1, 1) * b(2, 2) - b(1, 2) * b(2, 1))
+            - b(0, 1) * (b(1, 0) * b(2, 2) - b(1, 2) * b(2, 0))
+            + b(0, 2) * (b(1, 0) * b(2, 1) - b(1, 1) * b(2, 0));
+        let phi = (det / 2.0).clamp(-1.0, 1.0).acos() / 3.0;
+        let e1 = q + 2.0 * p * phi.cos();
+        let e3 = q + 2.0 * p * (phi + 2.0 * std::f64::consts::PI / 3.0).cos();
+        [e1, 3.0 * q - e1 - e3, e3]
+    };
+    ret.sort_by(|a, b| a.total_cmp(b));
+    ret
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::rotor::RotorType;
+
+/// masses in amu of the isotopes that can be named in a [Substitution]
+const ISOTOPES: [(&str, f64); 25] = [
+    ("H", 1.0078250),
+    ("1H", 1.0078250),
+    ("D", 2.0141018),
+    ("2H", 2.0141018),
+    ("T", 3.0160493),
+    ("3H", 3.0160493),
+    ("10B", 10.0129370),
+    ("11B", 11.0093054),
+    ("12C", 12.0000000),
+    ("13C", 13.0033548),
+    ("14N", 14.0030740),
+    ("15N", 15.0001089),
+    ("16O", 15.9949146),
+    ("17O", 16.9991317),
+    ("18O", 17.9991610),
+    ("19F", 18.9984032),
+    ("28Si", 27.9769271),
+    ("29Si", 28.9764947),
+    ("30Si", 29.9737702),
+    ("31P", 30.9737620),
+    ("32S", 31.9720707),
+    ("33S", 32.9714585),
+    ("34S", 33.9678669),
+    ("35Cl", 34.9688527),
+    ("37Cl", 36.9659026),
+];
+
+/// the substitution of a single atom in the geometry with another isotope,
+/// parsed from strings like `2=13C`, `5=D`, or `3=17.9991610`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Substitution {
+    /// the 1-based index of the atom in [crate::Summary::geom]
+    pub atom: usize,
+
+    /// the name of the isotope, as given by the user
+    pub isotope: String,
+
+    /// the mass of the isotope in amu
+    pub mass: f64,
+}
+
+impl FromStr for Substitution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((atom, isotope)) = s.split_once('=') else {
+            return Err(format!("expected ATOM=ISOTOPE, got '{s}'"));
+        };
+        let atom = atom
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid atom index in '{s}'"))?;
+        let isotope = isotope.trim();
+        let mass = match ISOTOPES.iter().find(|(name, _)| *name == isotope) {
+            Some((_, mass)) => *mass,
+            None => isotope
+                .parse()
+                .map_err(|_| format!("unrecognized isotope '{isotope}'"))?,
+        };
+        Ok(Self {
+            atom,
+            isotope: isotope.to_owned(),
+            mass,
+        })
+    }
+}
+
+impl Display for Substitution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.atom, self.isotope)
+    }
+}
+
+/// rotational constants predicted for an isotopologue of a
+/// [crate::Summary] by [crate::Summary::isotopologue]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Isotopologue {
+    /// the substitutions applied to the parent molecule
+    pub subs: Vec<Substitution>,
+
+    /// atomic masses in amu of the substituted molecule
+    pub masses: Vec<f64>,
+
+    /// the type of rotor of the substituted molecule, which can differ from
+    /// the parent's if the substitution breaks its symmetry
+    pub rotor: RotorType,
+
+    /// equilibrium rotational constants in MHz, computed from the principal
+    /// moments of the substituted molecule
+    pub rot_equil: Vec<f64>,
+
+    /// ground state rotational constants in MHz, estimated by carrying over
+    /// the parent's vibrational corrections Be − B0 to `rot_equil`. empty if
+    /// the parent doesn't have ground state rotational constants
+    pub rot0: Vec<f64>,
+}
+
+impl Isotopologue {
+    /// return a label for the substitutions in `self`, like `2=13C,5=D`
+    pub fn label(&self) -> String {
+        let subs: Vec<_> = self.subs.iter().map(|s| s.to_string()).collect();
+        subs.join(",")
+    }
+}
@@ -9,12 +9,26 @@ use std::{
 
 use coriolis::Coriol;
 use curvil::Curvil;
+use darling::DarlingDennison;
 use delta::Delta;
+use fc::ForceConstants;
+use isotope::{Isotopologue, Substitution};
 use lazy_static::lazy_static;
+use nielsen::Nielsen;
+use ped::Ped;
 use phi::Phi;
+use polyad::ResonancePolyad;
+use reduction::{Reduction, Representation};
 use regex::Regex;
+use rot_levels::{Level, RotEnergies};
+use rotor::{RotorType, ROTOR_EPS};
 use serde::{Deserialize, Serialize};
+use state_rots::StateRots;
 use symm::{Atom, Irrep, Molecule};
+use tau::TauPrime;
+use thermo::{Thermo, Variant};
+use vib_state::VibState;
+use wilson::Wilson;
 
 #[cfg(test)]
 mod tests;
@@ -30,8 +44,28 @@ macro_rules! write_dist_consts {
 }
 
 mod coriolis;
+mod darling;
 pub mod delta;
+pub mod error;
+pub mod fc;
+pub mod inertia;
+pub mod isotope;
+pub mod nielsen;
+pub mod ped;
 pub mod phi;
+pub mod polyad;
+pub mod reduction;
+pub mod rot_levels;
+pub mod rotor;
+pub mod semiexp;
+pub mod state_rots;
+pub mod tau;
+pub mod thermo;
+pub mod vib_state;
+pub mod vpt2;
+pub mod wilson;
+
+pub use error::ParseError;
 
 pub mod curvil {
     use serde::{Deserialize, Serialize};
@@ -56,6 +90,10 @@ const BAD_FLOAT: f64 = 999999999.9;
 /// threshold for discarding rotations and translations
 const ROTRANS_THRSH: f64 = 30.0;
 
+/// tolerance for the symmetry of the mean square amplitude matrices, which
+/// SPECTRO prints to 1e-8
+const MSA_TOL: f64 = 1e-6;
+
 /// threshold for computing irrep symmetries
 pub const SYMM_EPS: f64 = 1e-4;
 
@@ -100,12 +138,32 @@ lazy_static! {
     static ref PHI: Regex = Regex::new(r"(?i)(^  (p)?h(i)? [jk123]+ |^ He\b)").unwrap();
     static ref FERMI: Regex = Regex::new(r"(?i)^ INPUTED FERMI").unwrap();
     static ref CORIOL: Regex = Regex::new(r"(?i)^ INPUTED CORIOLIS").unwrap();
+    static ref DARLING: Regex = Regex::new(r"(?i)^ INPUTED DARLING-DENNISON").unwrap();
+    static ref DARLING_CONST: Regex = Regex::new(r"^ DARLING-DENNISON CONSTANTS FOR").unwrap();
     /// empty line
     static ref BLANK: Regex = Regex::new(r"^\s*$").unwrap();
     /// can also occur at the end of the CURVIL section
     static ref OPTDL: Regex = Regex::new(r"^ I OPTDL").unwrap();
     static ref COORD: Regex = Regex::new(r"VIBRATIONALLY AVERAGED COORDINATES").unwrap();
     static ref CURVIL: Regex = Regex::new(r"^ CURVILINEAR INTERNAL COORDINATES").unwrap();
+    static ref ALPHA: Regex = Regex::new(r"^ ALPHA FOR PRINCIPAL AXIS\s+([ABC])").unwrap();
+    /// line like '   10*       0.0349865206          (  1048.870 )'
+    static ref ALPHA_ROW: Regex = Regex::new(r"^\s*(\d+)\*?\s+(-?\d+\.\d+)\s+\(\s*-?\d+\.\d+\s*\)\s*$").unwrap();
+    static ref POLYAD: Regex = Regex::new(r"^ FERMI RESONANCE BETWEEN : (.*) AND (.*)$").unwrap();
+    static ref ENERGY_MATRIX: Regex = Regex::new(r"^ ENERGY MATRIX").unwrap();
+    static ref EIGEN: Regex = Regex::new(r"^ EIGENVALUES AND EIGENVECTORS").unwrap();
+    static ref XMAT: Regex = Regex::new(r"^ ANHARMONIC CONSTANTS X IN CM-1").unwrap();
+    static ref MSA: Regex = Regex::new(r"^ MEAN SQUARE HARMONIC AMPLITUDES AT\s+(\d+) K").unwrap();
+    static ref PED: Regex = Regex::new(r"^ HARMONIC POTENTIAL ENERGY DISTRIBUTION").unwrap();
+    static ref ZETA: Regex = Regex::new(r"^ ZETA MATRIX FOR COORDINATE DIRECTION :\s+([123])").unwrap();
+    static ref FC2: Regex = Regex::new(r"^ QUADRATIC FORCE CONSTANTS IN INTERNAL COORDINATES").unwrap();
+    static ref FC3: Regex = Regex::new(r"^ CUBIC FORCE CONSTANTS IN INTERNAL COORDINATES").unwrap();
+    static ref FC4: Regex = Regex::new(r"^ QUARTIC FORCE CONSTANTS IN INTERNAL COORDINATES").unwrap();
+    /// line like '     3          2          1        504.97388', where the value
+    /// can also be asterisks if it overflowed
+    static ref FC3_ROW: Regex = Regex::new(r"^\s+(\d+)\s+(\d+)\s+(\d+)\s+(\S+)\s*$").unwrap();
+    static ref ROT_LEVELS: Regex = Regex::new(r"^ ROTATIONAL ENERGY MATRIX FOR (A|S) REDUCTION WITH J=\s+(\d+)").unwrap();
+    static ref FC4_ROW: Regex = Regex::new(r"^\s+(\d+)\s+(\d+)\s+(\d+)\s+(\d+)\s+(\S+)\s*$").unwrap();
 }
 
 #[derive(Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -122,6 +180,13 @@ pub struct Summary {
     /// molecular geometry used in the other calculations
     pub geom: Molecule,
 
+    /// atomic masses in amu of the atoms in `geom`, from the W column of the
+    /// geometry for SPECTRO output files
+    pub masses: Vec<f64>,
+
+    /// the type of rotor described by the principal moments of `geom`
+    pub rotor: RotorType,
+
     /// symmetries of the vibrational modes, probably not the best name
     pub irreps: Vec<Irrep>,
 
@@ -142,6 +207,24 @@ pub struct Summary {
     /// sextic distortion coefficients
     pub phis: Phi,
 
+    /// the reduction of the distortion constants, if any, that was computed
+    /// from the other one by [Summary::fill_reduction] instead of being read
+    /// from the output file
+    pub derived_reduction: Option<Reduction>,
+
+    /// rotational constants predicted for an isotopologue by
+    /// [Summary::isotopologue], for printing alongside the parent's
+    pub isotopologue: Option<Isotopologue>,
+
+    /// quartic distortion coefficients in Nielsen's representation
+    pub nielsen: Nielsen,
+
+    /// quartic distortion coefficients in Wilson's representation
+    pub wilson: Wilson,
+
+    /// planar quartic distortion constants τ′
+    pub tau_prime: TauPrime,
+
     /// inputed type-1 and -2 fermi resonances. map of each mode to its
     /// equivalences. Note that these are not necessarily the resonances
     /// detected *or* used by spectro, only the ones reported in the "INPUTED
@@ -151,6 +234,9 @@ pub struct Summary {
     /// inputed coriolis resonances. map of modes to axes
     pub coriolis: coriolis::Coriol,
 
+    /// inputed darling-dennison resonances and their constants
+    pub darling_dennison: DarlingDennison,
+
     /// zero-point vibrational energy
     pub zpt: f64,
 
@@ -162,10 +248,68 @@ pub struct Summary {
 
     /// R(EQUIL) values of the curvilinear coordinates in `curvil`
     pub requil: Vec<f64>,
+
+    /// thermally-averaged (R(G)) values of the curvilinear coordinates in
+    /// `curvils`, in the same units as `requil`
+    pub rg: Vec<f64>,
+
+    /// internuclear distance matrix at the equilibrium geometry in Å, stored
+    /// as a full symmetric matrix indexed by atom
+    pub dist: Vec<Vec<f64>>,
+
+    /// anharmonic constants (xᵢⱼ) in cm⁻¹, stored as a full symmetric matrix
+    pub xmat: Vec<Vec<f64>>,
+
+    /// vibration-rotation interaction constants (αᵢ) in MHz, indexed by mode
+    /// and then principal axis (A, B, C). axes not reported by SPECTRO, such
+    /// as A and C for linear molecules, are left as 0
+    pub alphas: Vec<Vec<f64>>,
+
+    /// the Fermi resonance polyads diagonalized by SPECTRO to obtain `corr`
+    pub polyads: Vec<ResonancePolyad>,
+
+    /// quadratic, cubic, and quartic force constants in SPECTRO's internal
+    /// coordinates, in the units printed by SPECTRO. see [ForceConstants] for
+    /// how these relate to `curvils`
+    pub internal_fcs: ForceConstants,
+
+    /// matrices of mean square harmonic amplitudes between the internal
+    /// coordinates in `curvils`, keyed by temperature in K. bonds are in Å and
+    /// angles in radians, as labeled by SPECTRO. see
+    /// [Summary::rms_amplitudes] for the matrices SPECTRO prints for
+    /// redundant coordinates
+    pub msa: HashMap<usize, Vec<Vec<f64>>>,
+
+    /// harmonic potential energy distribution over the internal coordinates
+    pub ped: Ped,
+
+    /// Coriolis zeta matrices for each of the coordinate directions (x, y, z),
+    /// indexed by normal mode
+    pub zeta: [Vec<Vec<f64>>; 3],
+
+    /// every vibrational state in the STATE NO. section, including the ground
+    /// state, fundamentals, overtones, and combination bands, in the order
+    /// SPECTRO printed them
+    pub vib_states: Vec<VibState>,
+
+    /// rotational constants for every vibrational state, including the
+    /// overtones and combination states left out of `rots`
+    pub state_rots: StateRots,
+
+    /// vibrational inertia defects in amu Å² from the PERTURBED VIBRATIONAL
+    /// INERTIA DEFECT sections, as pairs of the quanta in each mode and the
+    /// defect, if SPECTRO printed one. these sections are printed for each
+    /// resonance, so the same state can appear more than once
+    pub perturbed_defects: Vec<(Vec<usize>, Option<f64>)>,
+
+    /// rotational energy levels for J = 0 to 3 from the ROTATIONAL ENERGY
+    /// LEVEL ANALYSIS section, for each vibrational state and reduction
+    pub rot_energies: Vec<RotEnergies>,
 }
 
-#[derive(Debug, PartialEq)]
-enum State {
+/// the section of a SPECTRO output file being parsed
+#[derive(Clone, Debug, PartialEq)]
+pub enum State {
     Fund,
     Corr,
     Geom,
@@ -176,8 +320,34 @@ enum State {
     Fermi1,
     Fermi2,
     Coriolis,
+    Darling,
+    DarlingConst,
     Curvil,
     Coords,
+    Xmat,
+    /// the index of the principal axis being read
+    Alpha(usize),
+    Polyad,
+    PolyadMat,
+    PolyadVec,
+    Fc2,
+    Fc3,
+    Fc4,
+    /// the temperature in K of the amplitudes being read
+    Msa(usize),
+    Ped,
+    /// the index of the coordinate direction being read
+    Zeta(usize),
+    TauPrime,
+    Nielsen,
+    Wilson,
+    InertiaDefect,
+    /// the BXS, BYS, and BZS values in the ROTATIONAL ENERGY LEVEL ANALYSIS
+    /// section
+    RotLevelConsts,
+    /// the J of the rotational energy levels being read
+    RotLevels(usize),
+    Dist,
     None,
 }
 
@@ -186,6 +356,22 @@ impl State {
         matches!(self, State::Fermi1 | State::Fermi2)
     }
 
+    fn is_alpha(&self) -> bool {
+        matches!(self, State::Alpha(_))
+    }
+
+    fn is_msa(&self) -> bool {
+        matches!(self, State::Msa(_))
+    }
+
+    fn is_zeta(&self) -> bool {
+        matches!(self, State::Zeta(_))
+    }
+
+    fn is_rot_levels(&self) -> bool {
+        matches!(self, State::RotLevels(_))
+    }
+
     fn is_coords(&self) -> bool {
         matches!(self, State::Coords)
     }
@@ -206,36 +392,58 @@ pub enum Recompute {
 }
 
 impl Summary {
+    /// construct a [Summary] from `filename`, panicking on any error. See
+    /// [Summary::try_new] for a fallible version
     pub fn new<P>(filename: P, recompute: Recompute) -> Self
     where
         P: AsRef<Path> + std::fmt::Debug,
     {
-        if filename.as_ref().is_dir() {
-            panic!("{filename:#?} is a directory");
+        match Self::try_new(filename, recompute) {
+            Ok(s) => s,
+            Err(e) => panic!("{e}"),
         }
-        let f = match std::fs::File::open(&filename) {
-            Ok(f) => f,
-            Err(e) => panic!("failed to open {filename:#?} with '{e}'"),
-        };
-        let ext = filename.as_ref().extension().unwrap_or_default();
+    }
+
+    /// construct a [Summary] from `filename`, which can be either a SPECTRO
+    /// output file or a JSON file produced by Rust spectro
+    pub fn try_new<P>(
+        filename: P,
+        recompute: Recompute,
+    ) -> Result<Self, ParseError>
+    where
+        P: AsRef<Path> + std::fmt::Debug,
+    {
+        let path = filename.as_ref();
+        if path.is_dir() {
+            return Err(ParseError::IsDir(path.to_path_buf()));
+        }
+        let f = std::fs::File::open(path).map_err(|err| ParseError::Io {
+            file: path.to_path_buf(),
+            line: None,
+            err,
+        })?;
+        let ext = path.extension().unwrap_or_default();
         if ext == "json" {
-            let f = std::fs::File::open(&filename).unwrap();
-            let output: spectro::Output = serde_json::from_reader(f).unwrap();
+            let output: spectro::Output =
+                serde_json::from_reader(f).map_err(|err| ParseError::Json {
+                    file: path.to_path_buf(),
+                    err,
+                })?;
             let mut ret = Summary::from(output);
             if let Recompute::Yes(eps) = recompute {
                 ret.irreps = Vec::new();
                 let pg = ret.compute_irreps(eps);
                 eprintln!("recomputing irreps in {pg}");
             }
-            return ret;
+            return Ok(ret);
         }
-        let lines = BufReader::new(f).lines().map_while(Result::ok);
+        let lines = BufReader::new(f).lines();
 
         let mut state = State::None;
         let mut skip = 0;
         let mut ret = Self::default();
         // these are for multi-line state descriptions
-        let mut vib_states = Vec::new();
+        let mut vib_states: Vec<usize> = Vec::new();
         let mut cur_zpt = 0.0;
         let mut cur_freq = 0.0;
         // keep track of the freqs from the LXM matrix to handle degenerate
@@ -245,15 +453,161 @@ impl Summary {
         let mut block = 0;
         // rotational constant variables
         let mut rot_good = false;
-        let mut rot_states = Vec::new();
-        'outer: for line in lines {
+        // SPECTRO prints both BZA and BZS blocks when it treats the molecule
+        // as an asymmetric top, and only the BZS blocks otherwise
+        let mut asym_rots = false;
+        // quanta of the current rotational state, in the order of the STATE
+        // NO. section, and the number of non-degenerate modes in that section
+        let mut rot_quanta: Vec<usize> = Vec::new();
+        let mut nondeg = None;
+        // quanta and A- and S-reduced rotational constants of the current
+        // state in the ROTATIONAL ENERGY LEVEL ANALYSIS section
+        let mut level_quanta: Vec<usize> = Vec::new();
+        let mut level_rots_a: Vec<f64> = Vec::new();
+        let mut level_rots_s: Vec<f64> = Vec::new();
+        // starting column of the current block of the xmat or polyad matrices
+        let mut xmat_col = 0;
+        for (lineno, line) in lines.enumerate() {
+            let line = line.map_err(|err| ParseError::Io {
+                file: path.to_path_buf(),
+                line: Some(lineno + 1),
+                err,
+            })?;
+            // construct a ParseError::Line for the current line
+            macro_rules! error {
+                ($($arg:tt)*) => {
+                    ParseError::Line {
+                        file: path.to_path_buf(),
+                        line: lineno + 1,
+                        state: state.clone(),
+                        text: line.clone(),
+                        msg: format!($($arg)*),
+                    }
+                };
+            }
             if skip > 0 {
                 skip -= 1;
             } else if line.contains("MOLECULAR PRINCIPAL GEOMETRY") {
                 skip = 2;
                 state = State::Geom;
             } else if state == State::Geom {
-                geom_handler(&line, &mut state, &mut ret);
+                geom_handler(&line, &mut state, &mut ret)
+                    .map_err(|e| error!("{e}"))?;
+            } else if line.contains("INTERNUCLEAR DISTANCE MATRIX") {
+                skip = 1;
+                state = State::Dist;
+                xmat_col = 0;
+            } else if state == State::Dist {
+                let done = !sym_mat_line(&line, &mut ret.dist, &mut xmat_col)
+                    .map_err(|e| error!("{e}"))?;
+                if done {
+                    state = State::None;
+                }
+            } else if line.contains("PERTURBED VIBRATIONAL INERTIA DEFECT") {
+                skip = 1;
+                state = State::InertiaDefect;
+            } else if state == State::InertiaDefect && line.is_empty() {
+                state = State::None;
+            } else if state == State::InertiaDefect {
+                // line like ' v 5 + v 6     -0.2670754     -0.0158430'
+                let sp: Vec<&str> = line.split_ascii_whitespace().collect();
+                if sp.len() < 3 {
+                    return Err(error!("expected at least 3 fields"));
+                }
+                let n = sp.len();
+                let quanta = inertia::parse_label(
+                    &sp[..n - 2].join(" "),
+                    ret.harm.len(),
+                )
+                .map_err(|e| error!("{e}"))?;
+                let v = parse_fc(sp[n - 2]).map_err(|e| error!("{e}"))?;
+                ret.perturbed_defects.push((quanta, v));
+            } else if let Some(c) = ROT_LEVELS.captures(&line) {
+                let j = parse_field(Some(&c[2])).map_err(|e| error!("{e}"))?;
+                // each state and reduction starts over at J = 0
+                if j == 0 {
+                    let (reduction, rots) = match &c[1] {
+                        "A" => (Reduction::A, level_rots_a.clone()),
+                        _ => (Reduction::S, level_rots_s.clone()),
+                    };
+                    ret.rot_energies.push(RotEnergies {
+                        quanta: level_quanta.clone(),
+                        reduction,
+                        rots,
+                        levels: Vec::new(),
+                    });
+                }
+                state = State::RotLevels(j);
+                // skip the blank line and the header
+                skip = 2;
+            } else if state.is_rot_levels() {
+                let State::RotLevels(j) = state else {
+                    unreachable!()
+                };
+                // line like '     3     0           8.3452798   250185.20  BC'
+                let sp: Vec<&str> = line.split_ascii_whitespace().collect();
+                if sp.len() < 3 {
+                    state = State::None;
+                    continue;
+                }
+                let kc = parse_field(Some(sp[0])).map_err(|e| error!("{e}"))?;
+                let ka = parse_field(Some(sp[1])).map_err(|e| error!("{e}"))?;
+                let v: f64 =
+                    parse_field(Some(sp[2])).map_err(|e| error!("{e}"))?;
+                let Some(last) = ret.rot_energies.last_mut() else {
+                    return Err(error!("energy levels before J = 0"));
+                };
+                last.levels.push(Level {
+                    j,
+                    ka,
+                    kc,
+                    energy: v * TO_MHZ,
+                });
+            } else if line.contains("DISTORTION CONSTANTS TAU PRIME") {
+                state = State::TauPrime;
+            } else if state == State::TauPrime && line.contains("TAUP") {
+                // line like ' TAUP aaaa   -0.0000191693   -0.5746805110'
+                let sp: Vec<&str> = line.split_ascii_whitespace().collect();
+                let v: f64 = parse_field(sp.get(3).copied())
+                    .map_err(|e| error!("{e}"))?;
+                let tau = &mut ret.tau_prime;
+                match sp[1] {
+                    "aaaa" => tau.aaaa = Some(v),
+                    "bbbb" => tau.bbbb = Some(v),
+                    "cccc" => tau.cccc = Some(v),
+                    "aabb" => tau.aabb = Some(v),
+                    "aacc" => tau.aacc = Some(v),
+                    "bbcc" => tau.bbcc = Some(v),
+                    _ => return Err(error!("failed to match '{}'", sp[1])),
+                }
+            } else if line.contains("NIELSEN CENTRIFUGAL DISTORTION CONSTANTS")
+            {
+                skip = 1;
+                state = State::Nielsen;
+            } else if line.contains("WILSON CENTRIFUGAL DISTORTION CONSTANTS") {
+                skip = 1;
+                state = State::Wilson;
+            } else if (state == State::Nielsen || state == State::Wilson)
+                && line.is_empty()
+            {
+                state = State::None;
+            } else if state == State::Nielsen || state == State::Wilson {
+                // line like ' DJ    0.0000011143        0.0334048859'
+                let sp: Vec<&str> = line.split_ascii_whitespace().collect();
+                let v: f64 = parse_field(sp.get(2).copied())
+                    .map_err(|e| error!("{e}"))?;
+                match (&state, sp[0]) {
+                    (State::Nielsen, "DJ") => ret.nielsen.d_j = Some(v),
+                    (State::Nielsen, "DJK") => ret.nielsen.d_jk = Some(v),
+                    (State::Nielsen, "DK") => ret.nielsen.d_k = Some(v),
+                    (State::Nielsen, "dJ") => ret.nielsen.delta_j = Some(v),
+                    (State::Nielsen, "R5") => ret.nielsen.r5 = Some(v),
+                    (State::Nielsen, "R6") => ret.nielsen.r6 = Some(v),
+                    (State::Wilson, "DJ") => ret.wilson.d_j = Some(v),
+                    (State::Wilson, "DJK") => ret.wilson.d_jk = Some(v),
+                    (State::Wilson, "DK") => ret.wilson.d_k = Some(v),
+                    _ => return Err(error!("failed to match '{}'", sp[0])),
+                }
             } else if line.contains("LXM MATRIX") {
                 skip = 2;
                 state = State::Lxm;
@@ -291,6 +645,244 @@ impl Summary {
                         }
                     }));
                 }
+            } else if XMAT.is_match(&line) {
+                state = State::Xmat;
+                skip = 1;
+                xmat_col = 0;
+            } else if state == State::Xmat {
+                let done = !sym_mat_line(&line, &mut ret.xmat, &mut xmat_col)
+                    .map_err(|e| error!("{e}"))?;
+                if done {
+                    state = State::None;
+                }
+            } else if let Some(c) = ALPHA.captures(&line) {
+                state = State::Alpha(match &c[1] {
+                    "A" => 0,
+                    "B" => 1,
+                    _ => 2,
+                });
+            } else if state.is_alpha() && ALPHA_ROW.is_match(&line) {
+                let State::Alpha(axis) = state else {
+                    unreachable!()
+                };
+                let c = ALPHA_ROW.captures(&line).unwrap();
+                let mode: usize =
+                    parse_field(Some(&c[1])).map_err(|e| error!("{e}"))?;
+                let v: f64 =
+                    parse_field(Some(&c[2])).map_err(|e| error!("{e}"))?;
+                if mode == 0 {
+                    return Err(error!("invalid mode number"));
+                }
+                if ret.alphas.len() < mode {
+                    ret.alphas.resize(mode, vec![0.0; 3]);
+                }
+                ret.alphas[mode - 1][axis] = v * TO_MHZ;
+            } else if let Some(c) = POLYAD.captures(&line) {
+                let mut states = Vec::new();
+                for s in [&c[1], &c[2]] {
+                    states.push(
+                        ResonancePolyad::parse_state(s)
+                            .ok_or_else(|| error!("invalid state '{s}'"))?,
+                    );
+                }
+                ret.polyads.push(ResonancePolyad {
+                    states,
+                    ..Default::default()
+                });
+                state = State::Polyad;
+            } else if state == State::Polyad && ENERGY_MATRIX.is_match(&line) {
+                state = State::PolyadMat;
+                skip = 1;
+                xmat_col = 0;
+            } else if state == State::Polyad && EIGEN.is_match(&line) {
+                state = State::PolyadVec;
+                skip = 1;
+                xmat_col = 0;
+            } else if matches!(state, State::PolyadMat | State::PolyadVec) {
+                let fields: Vec<_> = line.split_whitespace().collect();
+                // the state is only set after pushing a polyad
+                let polyad = ret.polyads.last_mut().unwrap();
+                if fields.is_empty() {
+                    state = State::Polyad;
+                } else if line.contains("--------") {
+                    continue;
+                } else if HEADER.is_match(&line) {
+                    let col: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    xmat_col = col.saturating_sub(1);
+                } else if !DISP.is_match(fields[0]) {
+                    // line of eigenvalues above the eigenvectors
+                    for v in fields {
+                        polyad.eigenvalues.push(
+                            parse_field(Some(v)).map_err(|e| error!("{e}"))?,
+                        );
+                    }
+                } else {
+                    let row: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    let row = row.saturating_sub(1);
+                    for (j, v) in fields[1..].iter().enumerate() {
+                        let v =
+                            parse_field(Some(*v)).map_err(|e| error!("{e}"))?;
+                        if state == State::PolyadMat {
+                            set_sym(&mut polyad.energy, row, xmat_col + j, v);
+                        } else {
+                            set_elem(
+                                &mut polyad.eigenvectors,
+                                row,
+                                xmat_col + j,
+                                v,
+                            );
+                        }
+                    }
+                }
+            } else if let Some(c) = ZETA.captures(&line) {
+                let axis: usize =
+                    parse_field(Some(&c[1])).map_err(|e| error!("{e}"))?;
+                state = State::Zeta(axis - 1);
+                skip = 1;
+                xmat_col = 0;
+            } else if state.is_zeta() {
+                let State::Zeta(axis) = state else {
+                    unreachable!()
+                };
+                let fields: Vec<_> = line.split_whitespace().collect();
+                if fields.is_empty() || line.contains("--------") {
+                    continue;
+                } else if HEADER.is_match(&line) {
+                    let col: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    xmat_col = col.saturating_sub(1);
+                } else if DISP.is_match(fields[0]) {
+                    let row: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    for (j, v) in fields[1..].iter().enumerate() {
+                        let v =
+                            parse_field(Some(*v)).map_err(|e| error!("{e}"))?;
+                        set_elem(
+                            &mut ret.zeta[axis],
+                            row.saturating_sub(1),
+                            xmat_col + j,
+                            v,
+                        );
+                    }
+                } else {
+                    state = State::None;
+                }
+            } else if let Some(c) = MSA.captures(&line) {
+                let temp =
+                    parse_field(Some(&c[1])).map_err(|e| error!("{e}"))?;
+                state = State::Msa(temp);
+                // skip down to the blank line before the first header
+                skip = 5;
+                xmat_col = 0;
+            } else if state.is_msa() {
+                let State::Msa(temp) = state else {
+                    unreachable!()
+                };
+                let fields: Vec<_> = line.split_whitespace().collect();
+                if fields.is_empty() || line.contains("--------") {
+                    continue;
+                } else if HEADER.is_match(&line) {
+                    let col: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    xmat_col = col.saturating_sub(1);
+                } else if DISP.is_match(fields[0]) {
+                    let row: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    let mat = ret.msa.entry(temp).or_default();
+                    for (j, v) in fields[1..].iter().enumerate() {
+                        let v =
+                            parse_field(Some(*v)).map_err(|e| error!("{e}"))?;
+                        set_elem(mat, row.saturating_sub(1), xmat_col + j, v);
+                    }
+                } else {
+                    state = State::None;
+                }
+            } else if PED.is_match(&line) {
+                state = State::Ped;
+                skip = 2;
+                xmat_col = 0;
+            } else if state == State::Ped {
+                let fields: Vec<_> = line.split_whitespace().collect();
+                if fields.is_empty() || line.contains("--------") {
+                    continue;
+                } else if HEADER.is_match(&line) {
+                    let col: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    xmat_col = col.saturating_sub(1);
+                } else if DISP.is_match(fields[0]) {
+                    let row: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    for (j, v) in fields[1..].iter().enumerate() {
+                        let v =
+                            parse_field(Some(*v)).map_err(|e| error!("{e}"))?;
+                        set_elem(
+                            &mut ret.ped.data,
+                            row.saturating_sub(1),
+                            xmat_col + j,
+                            v,
+                        );
+                    }
+                } else if let Ok(freqs) = fields
+                    .iter()
+                    .map(|s| s.parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                {
+                    // line of harmonic frequencies below the column header
+                    ret.ped.freqs.extend(freqs);
+                } else {
+                    state = State::None;
+                }
+            } else if FC2.is_match(&line) {
+                state = State::Fc2;
+                skip = 1;
+                xmat_col = 0;
+            } else if state == State::Fc2 {
+                let fields: Vec<_> = line.split_whitespace().collect();
+                if fields.is_empty() || line.contains("--------") {
+                    continue;
+                } else if HEADER.is_match(&line) {
+                    let col: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    xmat_col = col.saturating_sub(1);
+                } else if DISP.is_match(fields[0]) {
+                    let row: usize = parse_field(Some(fields[0]))
+                        .map_err(|e| error!("{e}"))?;
+                    for (j, v) in fields[1..].iter().enumerate() {
+                        let v = parse_fc(v).map_err(|e| error!("{e}"))?;
+                        ret.internal_fcs.insert(vec![row, xmat_col + j + 1], v);
+                    }
+                } else {
+                    state = State::None;
+                }
+            } else if FC3.is_match(&line) {
+                state = State::Fc3;
+                skip = 1;
+            } else if FC4.is_match(&line) {
+                state = State::Fc4;
+                skip = 1;
+            } else if matches!(state, State::Fc3 | State::Fc4) {
+                let captures = if state == State::Fc3 {
+                    FC3_ROW.captures(&line)
+                } else {
+                    FC4_ROW.captures(&line)
+                };
+                if let Some(c) = captures {
+                    let n = c.len() - 1;
+                    let mut key = Vec::with_capacity(n - 1);
+                    for i in 1..n {
+                        key.push(
+                            parse_field(Some(&c[i]))
+                                .map_err(|e| error!("{e}"))?,
+                        );
+                    }
+                    let v = parse_fc(&c[n]).map_err(|e| error!("{e}"))?;
+                    ret.internal_fcs.insert(key, v);
+                } else if !BLANK.is_match(&line) && !line.contains("INT COORD")
+                {
+                    state = State::None;
+                }
             } else if line.contains("BAND CENTER ANALYSIS") {
                 skip = 3;
                 state = State::Fund;
@@ -302,6 +894,9 @@ impl Summary {
                 && line.contains(|s: char| s.is_numeric())
             {
                 let fields: Vec<_> = line.split_whitespace().collect();
+                if fields.len() < 3 {
+                    return Err(error!("expected at least 3 fields"));
+                }
                 ret.harm.push(fields[1].parse().unwrap_or(BAD_FLOAT));
                 ret.fund.push(fields[2].parse().unwrap_or(BAD_FLOAT));
             } else if line.contains("STATE NO.") && !line.contains("SPECTRUM") {
@@ -314,79 +909,115 @@ impl Summary {
             } else if state == State::Corr && line.contains("NON-DEG (Vs)") {
                 vib_states.clear();
                 let fields: Vec<_> = line.split_whitespace().collect();
-                vib_states.extend(
-                    fields[6..].iter().map(|s| s.parse::<usize>().unwrap()),
-                );
+                if fields.len() < 6 {
+                    return Err(error!("expected at least 6 fields"));
+                }
+                for s in &fields[6..] {
+                    vib_states.push(
+                        parse_field(Some(*s)).map_err(|e| error!("{e}"))?,
+                    );
+                }
                 cur_zpt = fields[1].parse().unwrap_or(BAD_FLOAT);
                 cur_freq = fields[2].parse().unwrap_or(BAD_FLOAT);
             } else if state == State::Corr && line.contains("DEGEN   (Vt)") {
-                vib_states.extend(
-                    line.split_whitespace()
-                        .skip(3)
-                        .map(|s| s.parse::<usize>().unwrap()),
-                );
+                nondeg.get_or_insert(vib_states.len());
+                for s in line.split_whitespace().skip(3) {
+                    vib_states
+                        .push(parse_field(Some(s)).map_err(|e| error!("{e}"))?);
+                }
             } else if state == State::Corr && line.contains("DEGEN   (Vl)") {
                 // nothing for now, just eat the line after handling the count
                 // above in the Vt case
             } else if state == State::Corr && line.contains("<>") {
                 state = State::None;
             } else if state == State::Corr && !line.is_empty() {
-                vib_states.extend(
-                    line.split_whitespace()
-                        .map(|s| s.parse::<usize>().unwrap()),
-                );
+                for s in line.split_whitespace() {
+                    vib_states
+                        .push(parse_field(Some(s)).map_err(|e| error!("{e}"))?);
+                }
             } else if state == State::Corr
                 && line.is_empty()
                 && !vib_states.is_empty()
             {
-                if vib_states.iter().all(|s| *s == 0) {
+                // take the quanta so that a second blank line doesn't record
+                // the same state again
+                let vs = VibState {
+                    quanta: std::mem::take(&mut vib_states),
+                    zpt_rel: cur_freq,
+                    energy: cur_zpt,
+                };
+                if vs.is_ground() {
                     ret.zpt = cur_zpt;
-                } else {
-                    let mut one = false;
-                    let mut idx = 0;
-                    for (i, state) in vib_states.iter().enumerate() {
-                        if (*state == 1 && one) || *state == 2 {
-                            continue 'outer;
-                        } else if *state == 1 {
-                            idx = i;
-                            one = true;
-                        }
-                    }
+                } else if vs.is_fundamental() {
+                    // only fundamentals go in corr, the overtones and
+                    // combination bands are only in vib_states
+                    let idx = vs.quanta.iter().position(|q| *q == 1).unwrap();
                     if idx >= ret.corr.len() {
                         ret.corr.resize(idx + 1, 0.0);
                     }
                     ret.corr[idx] = cur_freq;
                 }
+                ret.vib_states.push(vs);
             } else if line.contains("NON-DEG(Vt)") {
-                rot_states.extend(
-                    line.split_whitespace().skip(2).map(|s| s.to_string()),
-                );
+                for s in line.split_whitespace().skip(2) {
+                    rot_quanta
+                        .push(parse_field(Some(s)).map_err(|e| error!("{e}"))?);
+                }
+            } else if line.contains("NON-DEG (Vt)") {
+                // symmetric tops and linear molecules pad both the
+                // non-degenerate and degenerate lines with zeros to the total
+                // number of modes
+                let nmodes =
+                    ret.vib_states.first().map_or(0, |v| v.quanta.len());
+                rot_quanta.clear();
+                for s in line
+                    .split_whitespace()
+                    .skip(3)
+                    .take(nondeg.unwrap_or(nmodes))
+                {
+                    rot_quanta
+                        .push(parse_field(Some(s)).map_err(|e| error!("{e}"))?);
+                }
+            } else if line.contains("DEGEN   (Vs)") {
+                let nmodes =
+                    ret.vib_states.first().map_or(0, |v| v.quanta.len());
+                let ndeg = nmodes
+                    .checked_sub(nondeg.unwrap_or(nmodes))
+                    .ok_or_else(|| {
+                        error!(
+                            "more non-degenerate modes than the {nmodes} in \
+                             the vibrational states"
+                        )
+                    })?;
+                for s in line.split_whitespace().skip(3).take(ndeg) {
+                    rot_quanta
+                        .push(parse_field(Some(s)).map_err(|e| error!("{e}"))?);
+                }
             } else if line.contains("ROTATIONAL ENERGY LEVEL ANALYSIS") {
                 state = State::RotA;
                 rot_good = true;
             } else if rot_good && line.contains("BZA") {
                 state = State::RotA;
-            } else if rot_good && line.contains("BZS") {
-                if ret.deltas.de.is_some() {
-                    // linear molecule
+                asym_rots = true;
+            } else if rot_good && !asym_rots && line.contains("BZS") {
+                if ret.rotor == RotorType::Linear {
                     state = State::RotLin;
-                } else if ret.rot_equil.len() == 2 {
-                    // symmetric top
+                } else {
                     state = State::RotS;
                 }
+            } else if rot_good && line.contains("BZS") {
+                state = State::RotLevelConsts;
+            } else if state == State::RotLevelConsts {
+                state = State::None;
+                level_rots_s = sorted_rots(&line);
             } else if COORD.is_match(&line) {
                 state = State::Coords;
-                skip = 12 + i32::from(ret.rot_equil.len() == 1);
+                // the symmetric top and linear molecule sections have an extra
+                // line for the degenerate modes in each state
+                skip = 12 + i32::from(!asym_rots);
             } else if state == State::RotA && rot_good {
-                let mut one = false;
-                for state in &rot_states {
-                    if (*state == "1" && one) || *state == "2" {
-                        continue 'outer;
-                    } else if *state == "1" {
-                        one = true;
-                    }
-                }
-                rot_states.clear();
+                let quanta = std::mem::take(&mut rot_quanta);
+                let fundamental = is_fundamental(&quanta);
                 state = State::None;
                 let fields: Vec<_> = line.split_whitespace().collect();
                 if fields.len() != 3 {
@@ -394,24 +1025,22 @@ impl Summary {
                     // sure why yet
                     continue;
                 }
-                let mut v: Vec<_> = fields
+                let v: Vec<_> = fields
                     .iter()
                     .map(|s| s.parse().unwrap_or(f64::NAN) * TO_MHZ)
                     .collect();
-                v.sort_by(|a, b| {
-                    b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal)
-                });
-                ret.rots.push(v);
-            } else if state == State::RotLin && rot_good {
-                let mut one = false;
-                for state in &rot_states {
-                    if (*state == "1" && one) || *state == "2" {
-                        continue 'outer;
-                    } else if *state == "1" {
-                        one = true;
-                    }
+                level_quanta.clone_from(&quanta);
+                level_rots_a = sorted_rots(&line);
+                // SPECTRO can still analyze symmetric tops as asymmetric tops,
+                // so this may need to be reduced too
+                let v = ret.rotor.unique_state_rots(&v);
+                if fundamental {
+                    ret.rots.push(v.clone());
                 }
-                rot_states.clear();
+                ret.state_rots.data.insert(quanta, v);
+            } else if state == State::RotLin && rot_good {
+                let quanta = std::mem::take(&mut rot_quanta);
+                let fundamental = is_fundamental(&quanta);
                 state = State::None;
                 let fields: Vec<_> = line.split_whitespace().collect();
                 if fields.len() != 3 {
@@ -433,17 +1062,20 @@ impl Summary {
                 // by abs moves the real one to the front. for some reason,
                 // spectro also reports it as the difference from equilibrium so
                 // add to that.
-                ret.rots.push(vec![v[0] + ret.rot_equil[0]]);
-            } else if state == State::RotS && rot_good {
-                let mut one = false;
-                for state in &rot_states {
-                    if (*state == "1" && one) || *state == "2" {
-                        continue 'outer;
-                    } else if *state == "1" {
-                        one = true;
-                    }
+                let Some(b) = ret.rot_equil.first() else {
+                    return Err(error!(
+                        "vibrationally-averaged constants before equilibrium \
+                         rotational constants"
+                    ));
+                };
+                let v = vec![v[0] + b];
+                if fundamental {
+                    ret.rots.push(v.clone());
                 }
-                rot_states.clear();
+                ret.state_rots.data.insert(quanta, v);
+            } else if state == State::RotS && rot_good {
+                let quanta = std::mem::take(&mut rot_quanta);
+                let fundamental = is_fundamental(&quanta);
                 state = State::None;
                 let fields: Vec<_> = line.split_whitespace().collect();
                 if fields.len() != 3 {
@@ -451,40 +1083,30 @@ impl Summary {
                     // sure why yet
                     continue;
                 }
-                let mut v: Vec<_> = fields
+                let v: Vec<_> = fields
                     .iter()
                     .map(|s| s.parse().unwrap_or(f64::NAN) * TO_MHZ)
                     .collect();
-                v.sort_by(|a, b| {
-                    b.abs()
-                        .partial_cmp(&a.abs())
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-                // for symmetric top molecules, there are only two unique
-                // rotational constants, but spectro still reports them
-                // separately. we typically handle this by averaging the two
-                // close ones in the output file and reporting the remaining two
-                // constants as a and c.
-                if (v[0] - v[1]).abs() < 0.1 {
-                    ret.rots.push(vec![(v[0] + v[1]) / 2.0, v[2]]);
-                } else if (v[1] - v[2]).abs() < 0.1 {
-                    ret.rots.push(vec![v[0], (v[1] + v[2]) / 2.0]);
-                } else {
-                    ret.rots.push(v);
+                // for symmetric and spherical tops, there are fewer unique
+                // rotational constants, but spectro still reports all three, so
+                // average the ones that should be equal unless the vibration
+                // split them
+                let v = ret.rotor.unique_state_rots(&v);
+                if fundamental {
+                    ret.rots.push(v.clone());
                 }
+                ret.state_rots.data.insert(quanta, v);
             } else if line.contains("Be") {
                 // line like  ' (Be =    1.64769 IN CM-1)'
-                ret.rot_equil.push(
-                    line.split_ascii_whitespace()
-                        .nth(2)
-                        .unwrap()
-                        .parse::<f64>()
-                        .unwrap_or(f64::NAN)
-                        * TO_MHZ,
-                );
+                let v = line
+                    .split_ascii_whitespace()
+                    .nth(2)
+                    .ok_or_else(|| error!("missing rotational constant"))?;
+                ret.rot_equil
+                    .push(v.parse::<f64>().unwrap_or(f64::NAN) * TO_MHZ);
             } else if DELTA.is_match(&line) {
                 let sp: Vec<&str> = line.split_ascii_whitespace().collect();
-                if sp.len() > 3 {
+                if sp.len() > 4 {
                     let v: f64 = sp[4].parse().unwrap_or(f64::NAN);
                     match (sp[0], sp[1]) {
                         // A reduction
@@ -499,18 +1121,22 @@ impl Summary {
                         ("D", "K") => ret.deltas.d_k = Some(v),
                         ("d", "1") => ret.deltas.d1 = Some(v),
                         ("d", "2") => ret.deltas.d2 = Some(v),
-                        _ => panic!(
-                            "failed to match '{}' and '{}'",
-                            sp[0], sp[1]
-                        ),
+                        _ => {
+                            return Err(error!(
+                                "failed to match '{}' and '{}'",
+                                sp[0], sp[1]
+                            ))
+                        }
                     }
-                } else {
+                } else if sp.len() > 2 {
                     // linear
                     ret.deltas.de = Some(sp[2].parse().unwrap_or(f64::NAN));
+                } else {
+                    return Err(error!("expected at least 3 fields"));
                 }
             } else if PHI.is_match(&line) {
                 let sp: Vec<&str> = line.split_ascii_whitespace().collect();
-                if sp.len() > 3 {
+                if sp.len() > 4 {
                     // phi is in Hz in the file, so turn it to MHz
                     let v: f64 = sp[4]
                         .replace('D', "E")
@@ -534,12 +1160,14 @@ impl Summary {
                         ("h", "1") => ret.phis.h1 = Some(v),
                         ("h", "2") => ret.phis.h2 = Some(v),
                         ("h", "3") => ret.phis.h3 = Some(v),
-                        _ => panic!(
-                            "failed to match '{}' and '{}'",
-                            sp[0], sp[1]
-                        ),
+                        _ => {
+                            return Err(error!(
+                                "failed to match '{}' and '{}'",
+                                sp[0], sp[1]
+                            ))
+                        }
                     }
-                } else {
+                } else if sp.len() > 2 {
                     // linear molecule
                     ret.phis.he = Some(
                         sp[2]
@@ -548,9 +1176,14 @@ impl Summary {
                             .unwrap_or(f64::NAN)
                             / 1e6,
                     );
+                } else {
+                    return Err(error!("expected at least 3 fields"));
                 }
             } else if FERMI.is_match(&line) {
-                let v = line.split_ascii_whitespace().nth(2).unwrap();
+                let v = line
+                    .split_ascii_whitespace()
+                    .nth(2)
+                    .ok_or_else(|| error!("missing resonance type"))?;
                 if v == "1" {
                     state = State::Fermi1;
                     skip = 2;
@@ -562,15 +1195,15 @@ impl Summary {
                 state = State::None;
             } else if state.is_fermi() {
                 let mut v = line.split_ascii_whitespace();
-                let a = v.next().unwrap().parse::<usize>().unwrap();
+                let a = parse_field(v.next()).map_err(|e| error!("{e}"))?;
                 if state == State::Fermi1 {
-                    let b = v.next().unwrap().parse::<usize>().unwrap();
+                    let b = parse_field(v.next()).map_err(|e| error!("{e}"))?;
                     let e = ret.fermi.entry(b).or_default();
                     e.push((a, a));
                 } else if state == State::Fermi2 {
                     // skip the + connecting two parts of a Fermi 2
-                    let b = v.nth(1).unwrap().parse::<usize>().unwrap();
-                    let c = v.next().unwrap().parse::<usize>().unwrap();
+                    let b = parse_field(v.nth(1)).map_err(|e| error!("{e}"))?;
+                    let c = parse_field(v.next()).map_err(|e| error!("{e}"))?;
                     let e = ret.fermi.entry(c).or_default();
                     e.push((a, b));
                 }
@@ -581,19 +1214,54 @@ impl Summary {
                 state = State::None;
             } else if state == State::Coriolis {
                 let mut v = line.split_ascii_whitespace();
-                let a = v.next().unwrap().parse::<usize>().unwrap();
-                let b = v.next().unwrap().parse::<usize>().unwrap();
-                let axis = v.next().unwrap().parse::<usize>().unwrap();
+                let a = parse_field(v.next()).map_err(|e| error!("{e}"))?;
+                let b = parse_field(v.next()).map_err(|e| error!("{e}"))?;
+                let axis = parse_field(v.next()).map_err(|e| error!("{e}"))?;
                 let e = ret.coriolis.data.entry((a, b)).or_default();
                 e.push(axis);
+            } else if DARLING.is_match(&line) {
+                state = State::Darling;
+                skip = 2;
+            } else if state == State::Darling && BLANK.is_match(&line) {
+                state = State::None;
+            } else if state == State::Darling {
+                let mut v = line.split_ascii_whitespace();
+                let a = parse_field(v.next()).map_err(|e| error!("{e}"))?;
+                let b = parse_field(v.next()).map_err(|e| error!("{e}"))?;
+                ret.darling_dennison.data.entry((a, b)).or_default();
+            } else if DARLING_CONST.is_match(&line) {
+                state = State::DarlingConst;
+                skip = 5;
+            } else if state == State::DarlingConst {
+                // line like
+                //     R  S   QUARTIC   CUBIC   CUBIC   ROT CON & ZETA   K RRSS
+                let v: Vec<_> = line.split_ascii_whitespace().collect();
+                if v.len() != 7 {
+                    state = State::None;
+                    continue;
+                }
+                let a = parse_field(Some(v[0])).map_err(|e| error!("{e}"))?;
+                let b = parse_field(Some(v[1])).map_err(|e| error!("{e}"))?;
+                let k = parse_field(Some(v[6])).map_err(|e| error!("{e}"))?;
+                ret.darling_dennison.data.insert((a, b), Some(k));
             } else if state.is_coords() && BLANK.is_match(&line) {
                 state = State::None;
             } else if state.is_coords() {
                 let v = line.split_ascii_whitespace().collect::<Vec<_>>();
                 // have to skip "ANGLE" for linear angles
-                let off = usize::from(v[1] == "LINEAR");
-                ret.requil.push(v[2 + off].parse().unwrap());
-                ret.ralpha.push(v[4 + off].parse().unwrap());
+                let off = usize::from(v.get(1) == Some(&"LINEAR"));
+                ret.requil.push(
+                    parse_field(v.get(2 + off).copied())
+                        .map_err(|e| error!("{e}"))?,
+                );
+                ret.rg.push(
+                    parse_field(v.get(3 + off).copied())
+                        .map_err(|e| error!("{e}"))?,
+                );
+                ret.ralpha.push(
+                    parse_field(v.get(4 + off).copied())
+                        .map_err(|e| error!("{e}"))?,
+                );
             } else if CURVIL.is_match(&line) {
                 state = State::Curvil;
                 skip = 4;
@@ -624,51 +1292,462 @@ impl Summary {
                     }
                 }
                 let mut v = new_line.split_ascii_whitespace();
-                let typ = v.nth(1).unwrap();
-                let ids: Vec<_> =
+                let typ = v
+                    .nth(1)
+                    .ok_or_else(|| error!("missing coordinate type"))?;
+                let mut ids: Vec<_> =
                     v.skip(1).step_by(2).flat_map(usize::from_str).collect();
+                if matches!(typ, "LINEAR" | "OUT-OF-PLANE") {
+                    // the label is actually "LINEAR ANGLE" or "OUT-OF-PLANE
+                    // BEND" so we have to re-process the line
+                    let v = new_line.split_ascii_whitespace();
+                    ids = v
+                        .skip(2)
+                        .step_by(2)
+                        .flat_map(usize::from_str)
+                        .collect();
+                }
+                let want = match typ {
+                    "BOND" => 2,
+                    "ANGLE" | "LINEAR" => 3,
+                    "TORSION" | "OUT-OF-PLANE" => 4,
+                    _ => return Err(error!("unrecognized curvil type {typ}")),
+                };
+                if ids.len() < want {
+                    return Err(error!(
+                        "expected {want} atoms for {typ}, found {}",
+                        ids.len()
+                    ));
+                }
                 use curvil::Curvil::*;
                 ret.curvils.push(match typ {
                     "BOND" => Bond(ids[0], ids[1]),
                     "ANGLE" => Angle(ids[0], ids[1], ids[2]),
                     "TORSION" => Torsion(ids[0], ids[1], ids[2], ids[3]),
-                    "LINEAR" => {
-                        // the label is actually "LINEAR ANGLE" so we have to
-                        // re-process the line
-                        let v = new_line.split_ascii_whitespace();
-                        let ids: Vec<_> = v
-                            .skip(2)
-                            .step_by(2)
-                            .flat_map(usize::from_str)
-                            .collect();
-                        Linear(ids[0], ids[1], ids[2])
-                    }
-                    "OUT-OF-PLANE" => {
-                        // the label is actually "OUT-OF-PLANE BEND" so we have
-                        // to re-process the line
-                        let v = new_line.split_ascii_whitespace();
-                        let ids: Vec<_> = v
-                            .skip(2)
-                            .step_by(2)
-                            .flat_map(usize::from_str)
-                            .collect();
-                        Opb(ids[0], ids[1], ids[2], ids[3])
-                    }
-                    _ => panic!("unrecognized curvil type {typ}"),
+                    "LINEAR" => Linear(ids[0], ids[1], ids[2]),
+                    "OUT-OF-PLANE" => Opb(ids[0], ids[1], ids[2], ids[3]),
+                    _ => unreachable!(),
                 });
             }
         }
+        // the asymmetric top section reports all three equilibrium constants
+        ret.rot_equil = ret.rotor.unique_rots(&ret.rot_equil);
         let pairs = zip(lxm_freqs, &ret.lxm).collect::<Vec<_>>();
         ret.lxm = pairs.iter().map(|p| p.1.clone()).collect();
         ret.compute_irreps(SYMM_EPS);
-        ret
+        Ok(ret)
+    }
+
+    /// fill in the missing reduction of the quartic and sextic distortion
+    /// constants by converting the other one in representation `rep`, using
+    /// the equilibrium rotational constants if available or the ground state
+    /// constants otherwise. returns the reduction that was derived, which is
+    /// also recorded in `self.derived_reduction`, or `None` if both or neither
+    /// reductions are present or the molecule is not an asymmetric top
+    pub fn fill_reduction(&mut self, rep: Representation) -> Option<Reduction> {
+        if self.rotor != RotorType::Asymmetric {
+            return None;
+        }
+        let rots = if self.rot_equil.len() == 3 {
+            self.rot_equil.clone()
+        } else {
+            self.rots.first().filter(|r| r.len() == 3)?.clone()
+        };
+        let (delta, phi) = (&mut self.deltas, &mut self.phis);
+        let reduction = if delta.has_a() && !delta.has_s() {
+            let s = delta.a_to_s(&rots, rep)?;
+            if let Some(p) = phi.a_to_s(delta, &rots, rep) {
+                phi.h_j = p.h_j;
+                phi.h_jk = p.h_jk;
+                phi.h_kj = p.h_kj;
+                phi.h_k = p.h_k;
+                phi.h1 = p.h1;
+                phi.h2 = p.h2;
+                phi.h3 = p.h3;
+            }
+            delta.d_j = s.d_j;
+            delta.d_jk = s.d_jk;
+            delta.d_k = s.d_k;
+            delta.d1 = s.d1;
+            delta.d2 = s.d2;
+            Reduction::S
+        } else if delta.has_s() && !delta.has_a() {
+            let a = delta.s_to_a(&rots, rep)?;
+            if let Some(p) = phi.s_to_a(delta, &rots, rep) {
+                phi.big_phi_j = p.big_phi_j;
+                phi.big_phi_k = p.big_phi_k;
+                phi.big_phi_jk = p.big_phi_jk;
+                phi.big_phi_kj = p.big_phi_kj;
+                phi.phi_j = p.phi_j;
+                phi.phi_jk = p.phi_jk;
+                phi.phi_k = p.phi_k;
+            }
+            delta.big_delta_j = a.big_delta_j;
+            delta.big_delta_k = a.big_delta_k;
+            delta.big_delta_jk = a.big_delta_jk;
+            delta.delta_j = a.delta_j;
+            delta.delta_k = a.delta_k;
+            Reduction::A
+        } else {
+            return None;
+        };
+        self.derived_reduction = Some(reduction);
+        Some(reduction)
+    }
+
+    /// return the inertia tensor in amu Å² of `self.geom` about its center of
+    /// mass, using the atomic masses in `self.masses`
+    pub fn inertia_tensor(&self) -> [[f64; 3]; 3] {
+        inertia::inertia_tensor(&self.geom, &self.masses)
+    }
+
+    /// return the principal moments of inertia in amu Å² of `self.geom` in
+    /// ascending order
+    pub fn principal_moments(&self) -> [f64; 3] {
+        inertia::principal_moments(&self.geom, &self.masses)
+    }
+
+    /// compute the equilibrium rotational constants in MHz from the principal
+    /// moments of `self.geom`, keeping only the unique constants for
+    /// `self.rotor` like in `self.rot_equil`
+    pub fn geom_rot_equil(&self) -> Vec<f64> {
+        let rots = inertia::rot_consts(self.principal_moments());
+        self.rotor.unique_rots(&rots)
+    }
+
+    /// predict the rotational constants of the isotopologue obtained by
+    /// applying `subs` to the masses of `self.geom`. the equilibrium constants
+    /// are computed from the principal moments of the substituted molecule,
+    /// and the ground state constants are estimated by assuming that the
+    /// vibrational corrections Be − B0 are the same as in `self`
+    pub fn isotopologue(
+        &self,
+        subs: &[Substitution],
+    ) -> Result<Isotopologue, String> {
+        if self.geom.atoms.is_empty() {
+            return Err(String::from("missing geometry"));
+        }
+        if self.masses.len() != self.geom.atoms.len() {
+            return Err(String::from("missing atomic masses for geometry"));
+        }
+        let mut masses = self.masses.clone();
+        for sub in subs {
+            if sub.atom == 0 || sub.atom > masses.len() {
+                return Err(format!(
+                    "atom {} out of range for {} atoms",
+                    sub.atom,
+                    masses.len()
+                ));
+            }
+            masses[sub.atom - 1] = sub.mass;
+        }
+        let moments = inertia::principal_moments(&self.geom, &masses);
+        let rotor = RotorType::from_moments(moments, ROTOR_EPS);
+        let rot_equil = rotor.unique_rots(&inertia::rot_consts(moments));
+
+        // match up the corrections by axis label, giving up if the parent
+        // doesn't have one of the constants we need
+        let mut rot0 = Vec::new();
+        if let Some(r0) = self.rots.first() {
+            let be = self.rotor.expand(&self.rot_equil);
+            let b0 = self.rotor.expand(r0);
+            for (j, (e, z)) in zip(be, b0).enumerate() {
+                let Some(r) = rotor.abc(&rot_equil, j) else {
+                    continue;
+                };
+                if let (Some(e), Some(z)) = (e, z) {
+                    rot0.push(r - (e - z));
+                } else {
+                    rot0.clear();
+                    break;
+                }
+            }
+        }
+
+        Ok(Isotopologue {
+            subs: subs.to_vec(),
+            masses,
+            rotor,
+            rot_equil,
+            rot0,
+        })
+    }
+
+    /// return the inertia defect of the equilibrium rotational constants in amu
+    /// Å², or `None` if `self` is not an asymmetric top
+    pub fn equil_inertia_defect(&self) -> Option<f64> {
+        inertia::inertia_defect(&self.rot_equil)
+    }
+
+    /// return the inertia defect in amu Å² of each set of vibrationally-averaged
+    /// rotational constants in `self.rots`
+    pub fn inertia_defects(&self) -> Vec<Option<f64>> {
+        self.rots
+            .iter()
+            .map(|rot| inertia::inertia_defect(rot))
+            .collect()
+    }
+
+    /// return the inertia defect in amu Å² of every vibrational state in
+    /// `self.state_rots`, in the order of [StateRots::keys]
+    pub fn state_inertia_defects(&self) -> Vec<(&Vec<usize>, Option<f64>)> {
+        self.state_rots
+            .keys()
+            .into_iter()
+            .map(|k| (k, inertia::inertia_defect(&self.state_rots.data[k])))
+            .collect()
+    }
+
+    /// compute the rotational energy levels up to `jmax` of the `state`th set
+    /// of rotational constants in `self.rots` by diagonalizing the
+    /// `reduction` of Watson's Hamiltonian in representation `rep`, with the
+    /// ground state distortion constants. the constants in `self.rots` are
+    /// the A-reduced ones for asymmetric tops, so they are only approximate
+    /// for the S reduction. see [rot_levels::energy_levels] for details
+    pub fn rot_levels(
+        &self,
+        state: usize,
+        reduction: Reduction,
+        rep: Representation,
+        jmax: usize,
+    ) -> Result<Vec<Level>, String> {
+        let rots = self.rots.get(state).ok_or_else(|| {
+            format!("no rotational constants for state {state}")
+        })?;
+        let [Some(a), Some(b), Some(c)] = self.rotor.expand(rots) else {
+            return Err(String::from(
+                "energy levels require all three rotational constants",
+            ));
+        };
+        let has = match reduction {
+            Reduction::A => self.deltas.has_a(),
+            Reduction::S => self.deltas.has_s(),
+        };
+        if !has {
+            return Err(format!("missing {reduction:?}-reduced constants"));
+        }
+        rot_levels::energy_levels(
+            &[a, b, c],
+            &self.deltas,
+            &self.phis,
+            reduction,
+            rep,
+            jmax,
+        )
+        .ok_or_else(|| String::from("failed to compute energy levels"))
+    }
+
+    /// return the point group of `self.geom` with tolerance `eps`, the same
+    /// one used to assign `self.irreps`
+    pub fn point_group(&self, eps: f64) -> symm::PointGroup {
+        self.geom.point_group_approx(eps)
+    }
+
+    /// compute the thermodynamic functions of the ideal gas at standard
+    /// pressure at each temperature in `temps`, using the frequencies and ZPE
+    /// from `variant` and the equilibrium rotational constants. unless it is
+    /// given in `sigma`, the rotational symmetry number is taken from the
+    /// point group of `self.geom` for asymmetric tops and linear molecules.
+    /// that point group is only the largest abelian subgroup, so for
+    /// symmetric and spherical tops the rotations of `self.geom` are counted
+    /// with [thermo::rotation_count] instead
+    pub fn thermo(
+        &self,
+        variant: Variant,
+        temps: &[f64],
+        sigma: Option<usize>,
+    ) -> Result<Thermo, String> {
+        if self.masses.is_empty() {
+            return Err(String::from("missing atomic masses"));
+        }
+        let rots = if self.rot_equil.is_empty() && !self.geom.atoms.is_empty() {
+            self.geom_rot_equil()
+        } else {
+            self.rot_equil.clone()
+        };
+        let sigma = match (sigma, self.rotor) {
+            (Some(sigma), _) => sigma,
+            (None, RotorType::Asymmetric | RotorType::Linear) => {
+                let pg = self.point_group(SYMM_EPS);
+                thermo::symmetry_number(&pg.to_string(), self.rotor)
+                    .ok_or_else(|| {
+                        format!(
+                            "unrecognized point group {pg}, set the symmetry \
+                             number with --symmetry-number"
+                        )
+                    })?
+            }
+            (None, _) => {
+                if self.geom.atoms.is_empty()
+                    || self.masses.len() != self.geom.atoms.len()
+                {
+                    return Err(format!(
+                        "the symmetry number of a {:?} top needs a geometry, \
+                         set it with --symmetry-number",
+                        self.rotor
+                    ));
+                }
+                thermo::rotation_count(&self.geom, &self.masses, SYMM_EPS)
+            }
+        };
+        let freqs = match variant {
+            Variant::Harmonic => &self.harm,
+            Variant::Fundamental | Variant::Anharmonic => &self.corr,
+        };
+        // the degenerate modes are only listed once
+        let nmodes = self.nmodes();
+        if freqs.len() > nmodes {
+            return Err(format!(
+                "{} frequencies for {nmodes} vibrational modes",
+                freqs.len()
+            ));
+        }
+        let freqs = thermo::expand_degenerate(freqs, nmodes - freqs.len());
+        let zpe = match variant {
+            Variant::Anharmonic => thermo::to_kj(self.zpt),
+            _ => thermo::to_kj(freqs.iter().sum::<f64>() / 2.0),
+        };
+        let species = thermo::Species {
+            mass: self.masses.iter().sum(),
+            rots: self.rotor.expand(&rots),
+            sigma,
+            freqs: &freqs,
+        };
+        Ok(Thermo {
+            variant,
+            sigma,
+            zpe,
+            rows: species.rows(temps),
+        })
+    }
+
+    /// return the number of vibrational modes, counting both components of
+    /// degenerate modes, from the number of atoms in `self.masses`
+    fn nmodes(&self) -> usize {
+        let natoms = self.masses.len();
+        match self.rotor {
+            RotorType::Linear => (3 * natoms).saturating_sub(5),
+            _ => (3 * natoms).saturating_sub(6),
+        }
+    }
+
+    /// predict the vibrational levels with at most `max_quanta` quanta and at
+    /// most `max_energy` cm⁻¹ above the ZPT from the VPT2 energy expression
+    /// with `self.harm` and `self.xmat`, sorted by energy. at least one of the
+    /// limits must be given. the symmetry of each level is the direct product
+    /// of `self.irreps`. molecules with degenerate modes are not supported,
+    /// since their energies also depend on the vibrational angular momentum
+    pub fn vpt2_levels(
+        &self,
+        max_quanta: Option<usize>,
+        max_energy: Option<f64>,
+    ) -> Result<Vec<vpt2::Level>, String> {
+        if max_quanta.is_none() && max_energy.is_none() {
+            return Err(String::from(
+                "either a number of quanta or an energy cutoff is required",
+            ));
+        }
+        let n = self.harm.len();
+        if self.xmat.len() != n || self.xmat.iter().any(|row| row.len() != n) {
+            return Err(String::from("missing anharmonic constants"));
+        }
+        if n != self.nmodes() {
+            return Err(String::from("degenerate modes are not supported"));
+        }
+        let mut ret: Vec<_> = vpt2::states(
+            &self.harm,
+            &self.xmat,
+            max_quanta.unwrap_or(usize::MAX),
+            max_energy.unwrap_or(f64::INFINITY),
+        )
+        .into_iter()
+        .map(|(quanta, e)| vpt2::Level {
+            symmetry: if self.irreps.len() == n {
+                vpt2::symmetry(&self.irreps, &quanta)
+            } else {
+                None
+            },
+            state: VibState {
+                quanta,
+                zpt_rel: e,
+                energy: self.zpt + e,
+            },
+        })
+        .collect();
+        ret.sort_by(|a, b| a.state.zpt_rel.total_cmp(&b.state.zpt_rel));
+        Ok(ret)
+    }
+
+    /// for each fundamental in `self.corr`, return the index in
+    /// `self.polyads` of the polyad mixing it most strongly, along with the
+    /// leading eigenvector coefficient of the eigenstate closest in energy to
+    /// the fundamental. fundamentals that are not part of any polyad are
+    /// `None`
+    pub fn leading_coeffs(&self) -> Vec<Option<(usize, f64)>> {
+        self.corr
+            .iter()
+            .enumerate()
+            .map(|(i, freq)| {
+                let fund = [(i + 1, 1)];
+                self.polyads
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.position(&fund).is_some())
+                    .filter_map(|(j, p)| {
+                        let (_, c) = p.leading(p.closest(*freq)?)?;
+                        Some((j, c))
+                    })
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            })
+            .collect()
+    }
+
+    /// return the fundamentals whose leading coefficient from
+    /// [Summary::leading_coeffs] is below `thresh`, as tuples of the index
+    /// into `self.corr`, the index into `self.polyads`, and the coefficient
+    pub fn mixed_states(&self, thresh: f64) -> Vec<(usize, usize, f64)> {
+        self.leading_coeffs()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, lead)| {
+                let (p, c) = lead?;
+                (c < thresh).then_some((i, p, c))
+            })
+            .collect()
+    }
+
+    /// return the root-mean-square amplitude of each coordinate in
+    /// `self.curvils` at `temp` K from the diagonal of the corresponding
+    /// matrix in `self.msa`. the rows of these matrices are the coordinates in
+    /// `curvils`, but when there are more of these than vibrational modes, as
+    /// for the 13 coordinates of C2H4, SPECTRO prints a matrix that is not
+    /// symmetric and has negative elements on the diagonal. every amplitude
+    /// is `None` in that case, and individual negative elements are also
+    /// `None`
+    pub fn rms_amplitudes(&self, temp: usize) -> Vec<Option<f64>> {
+        let Some(mat) = self.msa.get(&temp) else {
+            return vec![None; self.curvils.len()];
+        };
+        let symmetric = mat.iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, v)| {
+                mat.get(j)
+                    .and_then(|r| r.get(i))
+                    .is_some_and(|w| (v - w).abs() < MSA_TOL)
+            })
+        });
+        (0..self.curvils.len())
+            .map(|j| {
+                let m = *mat.get(j)?.get(j)?;
+                (symmetric && m >= 0.0).then(|| m.sqrt())
+            })
+            .collect()
     }
 
     /// compute irreps for the geometry and LXM matrix in `self`. compute the
     /// point group with SYMM_EPS but use starting_eps to determine the irreps
     /// within it
     fn compute_irreps(&mut self, starting_eps: f64) -> symm::PointGroup {
-        let pg = self.geom.point_group_approx(starting_eps);
+        let pg = self.point_group(starting_eps);
         for (i, disp) in self.lxm.iter().enumerate() {
             let mol = self.geom.clone() + disp.clone();
             let mut eps = starting_eps;
@@ -697,10 +1776,115 @@ impl Summary {
     }
 }
 
-fn geom_handler(line: &str, state: &mut State, ret: &mut Summary) {
+/// set both `mat[i][j]` and `mat[j][i]` to `v`, growing `mat` into a square
+/// matrix as needed
+fn set_sym(mat: &mut Vec<Vec<f64>>, i: usize, j: usize, v: f64) {
+    let n = i.max(j) + 1;
+    if mat.len() < n {
+        mat.resize(n, Vec::new());
+    }
+    for row in mat.iter_mut() {
+        if row.len() < n {
+            row.resize(n, 0.0);
+        }
+    }
+    mat[i][j] = v;
+    mat[j][i] = v;
+}
+
+/// parse a single `line` of a lower-triangular matrix printed in blocks of
+/// columns, like the anharmonic constants or the internuclear distances, into
+/// the symmetric matrix `mat`. `col` tracks the starting column of the current
+/// block. returns `Ok(false)` when `line` is not part of the matrix
+fn sym_mat_line(
+    line: &str,
+    mat: &mut Vec<Vec<f64>>,
+    col: &mut usize,
+) -> Result<bool, String> {
+    let fields: Vec<_> = line.split_whitespace().collect();
+    if fields.is_empty() || line.contains("--------") {
+        return Ok(true);
+    }
+    if HEADER.is_match(line) {
+        let c: usize = parse_field(Some(fields[0]))?;
+        *col = c.saturating_sub(1);
+    } else if DISP.is_match(fields[0]) {
+        let row: usize = parse_field(Some(fields[0]))?;
+        for (j, v) in fields[1..].iter().enumerate() {
+            set_sym(
+                mat,
+                row.saturating_sub(1),
+                *col + j,
+                parse_field(Some(v))?,
+            );
+        }
+    } else {
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// set `mat[i][j]` to `v`, growing `mat` as needed without making it square
+fn set_elem(mat: &mut Vec<Vec<f64>>, i: usize, j: usize, v: f64) {
+    if mat.len() <= i {
+        mat.resize(i + 1, Vec::new());
+    }
+    if mat[i].len() <= j {
+        mat[i].resize(j + 1, 0.0);
+    }
+    mat[i][j] = v;
+}
+
+/// returns `true` if `quanta`, the quanta printed before a set of rotational
+/// constants, describe the ground state or a fundamental
+fn is_fundamental(quanta: &[usize]) -> bool {
+    quanta.iter().sum::<usize>() <= 1
+}
+
+/// parse the three rotational constants in cm⁻¹ on `line` and return them in
+/// MHz, sorted in descending order
+fn sorted_rots(line: &str) -> Vec<f64> {
+    let mut v: Vec<_> = line
+        .split_whitespace()
+        .map(|s| s.parse().unwrap_or(f64::NAN) * TO_MHZ)
+        .collect();
+    v.sort_by(|a, b| b.total_cmp(a));
+    v
+}
+
+/// parse a force constant from `s`, returning `None` if SPECTRO printed
+/// asterisks because it overflowed the field width
+fn parse_fc(s: &str) -> Result<Option<f64>, String> {
+    if s.starts_with('*') {
+        Ok(None)
+    } else {
+        parse_field(Some(s)).map(Some)
+    }
+}
+
+/// parse the whitespace-separated field `s` as a `T`, returning a description
+/// of the problem if `s` is missing or invalid
+fn parse_field<T>(s: Option<&str>) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let s = s.ok_or_else(|| String::from("missing field"))?;
+    s.parse()
+        .map_err(|e| format!("failed to parse '{s}' with '{e}'"))
+}
+
+fn geom_handler(
+    line: &str,
+    state: &mut State,
+    ret: &mut Summary,
+) -> Result<(), String> {
     let fields: Vec<_> = line.split_whitespace().collect();
     if fields.is_empty() {
         *state = State::None;
+        ret.rotor = RotorType::from_moments(ret.principal_moments(), ROTOR_EPS);
+    } else if fields.len() < 5 {
+        return Err(String::from("expected 5 fields in geometry"));
     } else {
         let atomic_number = match ATOMIC_WEIGHTS.get(fields[4]) {
             Some(a) => *a,
@@ -712,14 +1896,13 @@ fn geom_handler(line: &str, state: &mut State, ret: &mut Summary) {
                 0
             }
         };
-        if let [x, y, z] = fields[1..=3]
-            .iter()
-            .map(|s| s.parse::<f64>().unwrap())
-            .collect::<Vec<_>>()[..]
-        {
-            ret.geom.atoms.push(Atom::new(atomic_number, x, y, z));
-        }
+        let x = parse_field(Some(fields[1]))?;
+        let y = parse_field(Some(fields[2]))?;
+        let z = parse_field(Some(fields[3]))?;
+        ret.masses.push(parse_field(Some(fields[4]))?);
+        ret.geom.atoms.push(Atom::new(atomic_number, x, y, z));
     }
+    Ok(())
 }
 
 impl Display for Summary {
@@ -760,18 +1943,15 @@ impl Display for Summary {
             f,
             "{:>5}{:15.1}{:15.1}{:15.1}",
             "e",
-            self.rot_equil.first().unwrap_or(&0.0),
-            self.rot_equil.get(1).unwrap_or(&0.0),
-            self.rot_equil.get(2).unwrap_or(&0.0)
+            self.rotor.abc(&self.rot_equil, 0).unwrap_or(&0.0),
+            self.rotor.abc(&self.rot_equil, 1).unwrap_or(&0.0),
+            self.rotor.abc(&self.rot_equil, 2).unwrap_or(&0.0)
         )?;
         for (i, rot) in self.rots.iter().enumerate() {
-            let mut v = rot.clone();
-            // sort in descending order
-            v.sort_by(|a, b| b.partial_cmp(a).unwrap());
             let (a, b, c) = (
-                v.first().unwrap_or(&0.0),
-                v.get(1).unwrap_or(&0.0),
-                v.get(2).unwrap_or(&0.0),
+                self.rotor.abc(rot, 0).unwrap_or(&0.0),
+                self.rotor.abc(rot, 1).unwrap_or(&0.0),
+                self.rotor.abc(rot, 2).unwrap_or(&0.0),
             );
             writeln!(f, "{i:5}{a:15.1}{b:15.1}{c:15.1}")?;
         }
@@ -788,44 +1968,36 @@ impl Display for Summary {
 
 impl From<spectro::Output> for Summary {
     fn from(value: spectro::Output) -> Self {
-        let mut rot_equil;
-        let mut rots: Vec<Vec<f64>>;
-        if value.linear {
-            rot_equil = vec![value.rot_equil[1] * TO_MHZ];
-            rots = value
+        // the output only records atomic numbers, so assume the most abundant
+        // isotope of each atom
+        let masses: Vec<_> = value
+            .geom
+            .atoms
+            .iter()
+            .map(|a| inertia::atomic_mass(a.atomic_number).unwrap_or(0.0))
+            .collect();
+        let moments = inertia::principal_moments(&value.geom, &masses);
+        let rotor = RotorType::from_moments(moments, ROTOR_EPS);
+        let rot_equil: Vec<_> = value.rot_equil.iter().map(to_mhz).collect();
+        let rot_equil = rotor.unique_rots(&rot_equil);
+        let rots = if rotor == RotorType::Linear {
+            // the vibrationally-averaged constants are reported as the
+            // difference from equilibrium
+            value
                 .rots
                 .iter()
                 .map(|r| vec![TO_MHZ * r.b + rot_equil[0]])
-                .collect();
+                .collect()
         } else {
-            rot_equil = value.rot_equil.iter().map(to_mhz).collect();
-            rots = value
+            value
                 .rots
                 .iter()
-                .map(|r| vec![TO_MHZ * r.a, TO_MHZ * r.b, TO_MHZ * r.c])
-                .collect();
-        }
-        // spectro reported 3 but two of them are the same
-        if rot_equil.len() == 3 {
-            let r = &rots[0];
-            let (a, b, c) = (r[0], r[1], r[2]);
-            let k = (2.0 * b - a - c) / (a - c);
-            if (k.abs() - 1.0).abs() < 1e-6 {
-                rot_equil = vec![
-                    value.rot_equil[0] * TO_MHZ,
-                    value.rot_equil[2] * TO_MHZ,
-                ];
-                // assertion again to guard against other symmetric top type
-                rots = value
-                    .rots
-                    .iter()
-                    .map(|r| {
-                        assert!((r.a - r.b).abs() < 1e-5);
-                        vec![TO_MHZ * r.a, TO_MHZ * r.c]
-                    })
-                    .collect();
-            }
-        }
+                .map(|r| {
+                    rotor
+                        .unique_state_rots(&[r.a, r.b, r.c].map(|r| r * TO_MHZ))
+                })
+                .collect()
+        };
 
         // why can't these be inferred?? the LSP filled it in fine
         let mut coriolis: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
@@ -842,25 +2014,53 @@ impl From<spectro::Output> for Summary {
         for spectro::Fermi2 { i, j, k } in value.resonances.fermi2 {
             fermi.entry(k + 1).or_default().push((i + 1, j + 1));
         }
+        let mut darling = HashMap::new();
+        for spectro::Darling { i, j } in value.resonances.darling {
+            darling.insert((i + 1, j + 1), None);
+        }
 
         Self {
             harm: value.harms,
             fund: value.funds,
             corr: value.corrs,
             geom: value.geom,
+            masses,
+            rotor,
             irreps: value.irreps,
             lxm: value.lxm,
             rots,
             rot_equil,
             deltas: value.quartic.into(),
             phis: value.sextic.into(),
+            derived_reduction: None,
+            isotopologue: None,
             fermi,
             coriolis: Coriol { data: coriolis },
+            darling_dennison: DarlingDennison { data: darling },
             zpt: value.zpt,
             // these are not computed by my spectro
             curvils: vec![],
             ralpha: vec![],
             requil: vec![],
+            rg: vec![],
+            dist: vec![],
+            // not included in the Output from my spectro
+            xmat: vec![],
+            alphas: vec![],
+            polyads: vec![],
+            internal_fcs: ForceConstants::default(),
+            msa: HashMap::new(),
+            ped: Ped::default(),
+            // Output has no field for the Coriolis zeta constants, so these
+            // are only filled from SPECTRO output files
+            zeta: Default::default(),
+            vib_states: vec![],
+            state_rots: StateRots::default(),
+            nielsen: Nielsen::default(),
+            wilson: Wilson::default(),
+            tau_prime: TauPrime::default(),
+            perturbed_defects: vec![],
+            rot_energies: vec![],
         }
     }
 }
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// quartic centrifugal distortion constants in Nielsen's representation, in
+/// MHz
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Nielsen {
+    pub d_j: Option<f64>,
+    pub d_jk: Option<f64>,
+    pub d_k: Option<f64>,
+    pub delta_j: Option<f64>,
+    pub r5: Option<f64>,
+    pub r6: Option<f64>,
+}
+
+impl Display for Nielsen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_dist_consts!(f, self,
+        d_j => "DJ",
+        d_jk => "DJK",
+        d_k => "DK",
+        delta_j => "dJ",
+        r5 => "R5",
+        r6 => "R6",
+           );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// tolerance for matching a harmonic frequency to the two-decimal frequencies
+/// labeling the columns of the PED
+const FREQ_TOL: f64 = 1e-2;
+
+/// the harmonic potential energy distribution (PED) printed by SPECTRO
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ped {
+    /// harmonic frequencies labeling the columns of `data`, in cm⁻¹. unlike
+    /// [crate::Summary::harm], both components of degenerate modes are
+    /// included
+    pub freqs: Vec<f64>,
+
+    /// contribution of each of SPECTRO's internal or symmetry coordinates
+    /// (rows) to each normal mode (columns), as printed. these coordinates
+    /// are only the same as [crate::Summary::curvils] when there are as many
+    /// of them, and the elements of each column sum to 1 but can be negative
+    /// or greater than 1
+    pub data: Vec<Vec<f64>>,
+}
+
+impl Ped {
+    /// return the index of the column corresponding to the harmonic frequency
+    /// `harm`
+    pub fn column(&self, harm: f64) -> Option<usize> {
+        self.freqs.iter().position(|f| (f - harm).abs() < FREQ_TOL)
+    }
+
+    /// return the (coordinate index, fraction) pairs making up the normal mode
+    /// with harmonic frequency `harm`, sorted by decreasing fraction. the
+    /// fractions are the absolute values of the PED elements normalized to
+    /// sum to 1
+    pub fn contributions(&self, harm: f64) -> Vec<(usize, f64)> {
+        let Some(col) = self.column(harm) else {
+            return Vec::new();
+        };
+        let mut ret: Vec<_> = self
+            .data
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| row.get(col).map(|v| (i, v.abs())))
+            .collect();
+        let total: f64 = ret.iter().map(|(_, v)| v).sum();
+        if total > 0.0 {
+            for (_, v) in ret.iter_mut() {
+                *v /= total;
+            }
+        }
+        ret.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        ret
+    }
+
+    /// whether the rows of `data` can be labeled by the `ncurvils`
+    /// coordinates in [crate::Summary::curvils]. SPECTRO drops to a
+    /// non-redundant set of coordinates when there are more curvilinear
+    /// coordinates than modes, and the rows no longer line up with them
+    pub fn matches_curvils(&self, ncurvils: usize) -> bool {
+        self.data.len() == ncurvils
+    }
+}
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::{delta::Delta, reduction::Representation};
+
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Phi {
     // a reduction
@@ -47,6 +49,120 @@ impl Phi {
     }
 }
 
+impl Phi {
+    /// report whether `self` contains any nonzero A-reduced constants. the
+    /// output from my spectro includes zeros for constants it did not compute
+    pub fn has_a(&self) -> bool {
+        [
+            self.big_phi_j,
+            self.big_phi_k,
+            self.big_phi_jk,
+            self.big_phi_kj,
+            self.phi_j,
+            self.phi_jk,
+            self.phi_k,
+        ]
+        .iter()
+        .flatten()
+        .any(|&p| p != 0.0)
+    }
+
+    /// report whether `self` contains any nonzero S-reduced constants
+    pub fn has_s(&self) -> bool {
+        [
+            self.h_j, self.h_jk, self.h_kj, self.h_k, self.h1, self.h2, self.h3,
+        ]
+        .iter()
+        .flatten()
+        .any(|&p| p != 0.0)
+    }
+
+    /// compute the S-reduced constants from the A-reduced constants in `self`
+    /// and the rotational constants `rots` in representation `rep`. unlike
+    /// the quartic constants, these conversions also depend on the S-reduced
+    /// quartic constants, which are taken from `delta` or computed from its
+    /// A-reduced constants if necessary. only the S-reduced fields of the
+    /// result are set, and `None` is returned if any required constant is
+    /// missing or `rots` is not an asymmetric top
+    pub fn a_to_s(
+        &self,
+        delta: &Delta,
+        rots: &[f64],
+        rep: Representation,
+    ) -> Option<Self> {
+        let (sigma, diff) = rep.sigma(rots)?;
+        let (d_jk, d_k, d1, d2) = s_quartics(delta, rots, rep)?;
+        let s2 = sigma * sigma;
+        let h3 = 3.0
+            * (self.phi_k?
+                + (32.0 * (s2 - 2.0) * d2 * d2 + 16.0 * d_k * d2) / diff)
+            / (32.0 * s2 + 27.0);
+        let h2 = (self.phi_jk?
+            + 10.0 * h3
+            + (32.0 * d2 * d2 + 8.0 * d2 * (d_jk + 2.0 * sigma * d1)) / diff)
+            / (4.0 * sigma);
+        Some(Self {
+            h_j: Some(self.big_phi_j? - 2.0 * h2),
+            h_jk: Some(self.big_phi_jk? + 12.0 * h2 - 16.0 * sigma * h3),
+            h_kj: Some(self.big_phi_kj? - 10.0 * h2 + 160.0 * sigma * h3 / 3.0),
+            h_k: Some(self.big_phi_k? - 112.0 * sigma * h3 / 3.0),
+            h1: Some(self.phi_j? - h3),
+            h2: Some(h2),
+            h3: Some(h3),
+            ..Default::default()
+        })
+    }
+
+    /// compute the A-reduced constants from the S-reduced constants in `self`
+    /// and the rotational constants `rots` in representation `rep`, using
+    /// the S-reduced quartic constants from `delta` as in [Phi::a_to_s]. only
+    /// the A-reduced fields of the result are set
+    pub fn s_to_a(
+        &self,
+        delta: &Delta,
+        rots: &[f64],
+        rep: Representation,
+    ) -> Option<Self> {
+        let (sigma, diff) = rep.sigma(rots)?;
+        let (d_jk, d_k, d1, d2) = s_quartics(delta, rots, rep)?;
+        let s2 = sigma * sigma;
+        let (h2, h3) = (self.h2?, self.h3?);
+        Some(Self {
+            big_phi_j: Some(self.h_j? + 2.0 * h2),
+            big_phi_k: Some(self.h_k? + 112.0 * sigma * h3 / 3.0),
+            big_phi_jk: Some(self.h_jk? - 12.0 * h2 + 16.0 * sigma * h3),
+            big_phi_kj: Some(self.h_kj? + 10.0 * h2 - 160.0 * sigma * h3 / 3.0),
+            phi_j: Some(self.h1? + h3),
+            phi_jk: Some(
+                4.0 * sigma * h2
+                    - 10.0 * h3
+                    - (32.0 * d2 * d2 + 8.0 * d2 * (d_jk + 2.0 * sigma * d1))
+                        / diff,
+            ),
+            phi_k: Some(
+                (32.0 * s2 + 27.0) * h3 / 3.0
+                    - (32.0 * (s2 - 2.0) * d2 * d2 + 16.0 * d_k * d2) / diff,
+            ),
+            ..Default::default()
+        })
+    }
+}
+
+/// return the S-reduced quartic constants D_JK, D_K, d1, and d2 needed for the
+/// sextic conversions, either directly from `delta` or by converting its
+/// A-reduced constants
+fn s_quartics(
+    delta: &Delta,
+    rots: &[f64],
+    rep: Representation,
+) -> Option<(f64, f64, f64, f64)> {
+    if delta.has_s() {
+        return Some((delta.d_jk?, delta.d_k?, delta.d1?, delta.d2?));
+    }
+    let s = delta.a_to_s(rots, rep)?;
+    Some((s.d_jk?, s.d_k?, s.d1?, s.d2?))
+}
+
 impl Display for Phi {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write_dist_consts! {
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+/// a set of vibrational states coupled by a Fermi resonance, along with the
+/// energy matrix SPECTRO diagonalized to obtain their corrected energies
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResonancePolyad {
+    /// the participating states as lists of (mode, quanta) pairs. for example,
+    /// 2ν₅ is `[(5, 2)]` and ν₅ + ν₆ is `[(5, 1), (6, 1)]`
+    pub states: Vec<Vec<(usize, usize)>>,
+
+    /// energy matrix in the basis of `states`, in cm⁻¹
+    pub energy: Vec<Vec<f64>>,
+
+    /// eigenvalues of `energy`, in cm⁻¹
+    pub eigenvalues: Vec<f64>,
+
+    /// eigenvectors of `energy`, where `eigenvectors[i][j]` is the
+    /// coefficient of `states[i]` in the eigenvector corresponding to
+    /// `eigenvalues[j]`
+    pub eigenvectors: Vec<Vec<f64>>,
+}
+
+impl ResonancePolyad {
+    /// parse a state label like `2v 5` or `v 5 + v 6` from the header of a
+    /// FERMI RESONANCE BETWEEN block into (mode, quanta) pairs
+    pub(crate) fn parse_state(s: &str) -> Option<Vec<(usize, usize)>> {
+        let mut ret = Vec::new();
+        for term in s.split('+') {
+            let (quanta, mode) = term.trim().split_once('v')?;
+            let quanta = if quanta.is_empty() {
+                1
+            } else {
+                quanta.trim().parse().ok()?
+            };
+            ret.push((mode.trim().parse().ok()?, quanta));
+        }
+        Some(ret)
+    }
+
+    /// return the index of `state` in `self.states`, if present
+    pub fn position(&self, state: &[(usize, usize)]) -> Option<usize> {
+        self.states.iter().position(|s| s == state)
+    }
+
+    /// return the index of the eigenvalue closest to `energy`
+    pub fn closest(&self, energy: f64) -> Option<usize> {
+        self.eigenvalues
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - energy).abs().total_cmp(&(*b - energy).abs())
+            })
+            .map(|(i, _)| i)
+    }
+
+    /// return the index of the state with the largest coefficient (in absolute
+    /// value) in eigenvector `j`, along with the absolute value of that
+    /// coefficient
+    pub fn leading(&self, j: usize) -> Option<(usize, f64)> {
+        self.eigenvectors
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| row.get(j).map(|c| (i, c.abs())))
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// format `state` like `2v5` or `v5 + v6`
+    pub fn state_label(state: &[(usize, usize)]) -> String {
+        state
+            .iter()
+            .map(|(mode, quanta)| {
+                if *quanta == 1 {
+                    format!("v{mode}")
+                } else {
+                    format!("{quanta}v{mode}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    /// format the states in `self` like `2v5 = v3`
+    pub fn label(&self) -> String {
+        self.states
+            .iter()
+            .map(|s| Self::state_label(s))
+            .collect::<Vec<_>>()
+            .join(" = ")
+    }
+}
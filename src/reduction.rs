@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// the two standard reductions of Watson's asymmetric top Hamiltonian
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Reduction {
+    A,
+    S,
+}
+
+/// Watson's identification of the principal axes a, b, and c with the x, y,
+/// and z axes of the Hamiltonian. only the right-handed representations used
+/// for the A and S reductions are included
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Representation {
+    /// x = b, y = c, z = a, the usual choice for near-prolate tops
+    #[default]
+    Ir,
+
+    /// x = a, y = b, z = c, the usual choice for near-oblate tops
+    IIIr,
+}
+
+impl Representation {
+    /// return the rotational constants Bx, By, and Bz for the rotational
+    /// constants in `rots`, or `None` if `rots` does not contain three
+    /// constants. the constants are sorted first, so their order in `rots`
+    /// does not matter
+    pub fn xyz(&self, rots: &[f64]) -> Option<[f64; 3]> {
+        let [a, b, c] = rots else {
+            return None;
+        };
+        let mut v = [*a, *b, *c];
+        v.sort_by(|a, b| b.total_cmp(a));
+        let [a, b, c] = v;
+        match self {
+            Representation::Ir => Some([b, c, a]),
+            Representation::IIIr => Some([a, b, c]),
+        }
+    }
+
+    /// return Watson's asymmetry parameter σ = (2Bz - Bx - By) / (Bx - By) and
+    /// the difference Bx - By for the rotational constants in `rots`, or
+    /// `None` if `rots` does not contain three constants
+    pub fn sigma(&self, rots: &[f64]) -> Option<(f64, f64)> {
+        let [x, y, z] = self.xyz(rots)?;
+        let diff = x - y;
+        Some(((2.0 * z - x - y) / diff, diff))
+    }
+}
+
+impl FromStr for Representation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ir" => Ok(Self::Ir),
+            "iiir" => Ok(Self::IIIr),
+            _ => Err(format!("unrecognized representation '{s}'")),
+        }
+    }
+}
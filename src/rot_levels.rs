@@ -0,0 +1,327 @@
+//! asymmetric top rotational energy levels and transitions from the Watson
+//! Hamiltonian
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    delta::Delta,
+    phi::Phi,
+    reduction::{Reduction, Representation},
+};
+
+/// maximum number of sweeps in the Jacobi eigenvalue algorithm
+const MAX_SWEEPS: usize = 100;
+
+/// a rotational energy level
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Level {
+    pub j: usize,
+    pub ka: usize,
+    pub kc: usize,
+
+    /// energy above the J = 0 level in MHz
+    pub energy: f64,
+}
+
+/// the rotational energy levels SPECTRO printed for a single vibrational state
+/// and reduction in the ROTATIONAL ENERGY LEVEL ANALYSIS section
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RotEnergies {
+    /// the quanta in each mode, in the same order as the keys of
+    /// [crate::Summary::state_rots]
+    pub quanta: Vec<usize>,
+
+    pub reduction: Reduction,
+
+    /// the rotational constants SPECTRO used for this reduction in MHz,
+    /// sorted in descending order
+    pub rots: Vec<f64>,
+
+    /// the levels for J = 0 to 3, in the order SPECTRO printed them
+    pub levels: Vec<Level>,
+}
+
+/// the principal axis along which the dipole moment component driving a
+/// transition lies
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DipoleType {
+    A,
+    B,
+    C,
+}
+
+/// a rotational transition between two [Level]s
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    pub upper: Level,
+    pub lower: Level,
+
+    /// frequency in MHz
+    pub freq: f64,
+
+    pub dipole: DipoleType,
+}
+
+/// the matrix elements of Watson's A- or S-reduced Hamiltonian in the
+/// symmetric top basis |J, K⟩
+struct Watson {
+    /// rotational constants Bx, By, and Bz
+    b: [f64; 3],
+
+    reduction: Reduction,
+
+    /// coefficients of J⁴, J²Jz², Jz⁴, J⁶, J⁴Jz², J²Jz⁴, and Jz⁶ on the
+    /// diagonal, including the minus signs on the quartic terms
+    diag: [f64; 7],
+
+    /// δJ, δK, φJ, φJK, and φK in the A reduction, or d1, d2, h1, h2, and h3
+    /// in the S reduction
+    off: [f64; 5],
+}
+
+impl Watson {
+    fn new(
+        b: [f64; 3],
+        deltas: &Delta,
+        phis: &Phi,
+        reduction: Reduction,
+    ) -> Self {
+        let (d, p) = (deltas, phis);
+        let (diag, off) = match reduction {
+            Reduction::A => (
+                [
+                    -d.big_delta_j.unwrap_or(0.0),
+                    -d.big_delta_jk.unwrap_or(0.0),
+                    -d.big_delta_k.unwrap_or(0.0),
+                    p.big_phi_j.unwrap_or(0.0),
+                    p.big_phi_jk.unwrap_or(0.0),
+                    p.big_phi_kj.unwrap_or(0.0),
+                    p.big_phi_k.unwrap_or(0.0),
+                ],
+                [d.delta_j, d.delta_k, p.phi_j, p.phi_jk, p.phi_k],
+            ),
+            Reduction::S => (
+                [
+                    -d.d_j.unwrap_or(0.0),
+                    -d.d_jk.unwrap_or(0.0),
+                    -d.d_k.unwrap_or(0.0),
+                    p.h_j.unwrap_or(0.0),
+                    p.h_jk.unwrap_or(0.0),
+                    p.h_kj.unwrap_or(0.0),
+                    p.h_k.unwrap_or(0.0),
+                ],
+                [d.d1, d.d2, p.h1, p.h2, p.h3],
+            ),
+        };
+        Self {
+            b,
+            reduction,
+            diag,
+            off: off.map(|v| v.unwrap_or(0.0)),
+        }
+    }
+
+    /// return the matrix element ⟨J, `k1`|H|J, `k2`⟩
+    fn elem(&self, j: usize, k1: i64, k2: i64) -> f64 {
+        let n = (j * (j + 1)) as f64;
+        let k = k1.min(k2);
+        // ⟨J, K + 2|J₊²|J, K⟩
+        let f = |k: i64| {
+            let k = k as f64;
+            ((n - k * (k + 1.0)) * (n - (k + 1.0) * (k + 2.0)))
+                .max(0.0)
+                .sqrt()
+        };
+        let kf = k as f64;
+        let [bx, by, bz] = self.b;
+        match ((k1 - k2).abs(), self.reduction) {
+            (0, _) => {
+                let ksq = kf * kf;
+                let d = self.diag;
+                (bx + by) / 2.0 * (n - ksq)
+                    + bz * ksq
+                    + d[0] * n * n
+                    + d[1] * n * ksq
+                    + d[2] * ksq * ksq
+                    + d[3] * n * n * n
+                    + d[4] * n * n * ksq
+                    + d[5] * n * ksq * ksq
+                    + d[6] * ksq * ksq * ksq
+            }
+            (2, Reduction::A) => {
+                let [dj, dk, pj, pjk, pk] = self.off;
+                let m = f(k);
+                // the anticommutators {Jz^n, Jx² - Jy²} pick up the diagonal
+                // elements on both sides
+                let s2 = (kf + 2.0).powi(2) + kf.powi(2);
+                let s4 = (kf + 2.0).powi(4) + kf.powi(4);
+                (bx - by) / 4.0 * m - dj * n * m - dk * s2 * m / 2.0
+                    + pj * n * n * m
+                    + pjk * n * s2 * m / 2.0
+                    + pk * s4 * m / 2.0
+            }
+            (2, Reduction::S) => {
+                let [d1, _, h1, _, _] = self.off;
+                let m = f(k);
+                (bx - by) / 4.0 * m + d1 * n * m + h1 * n * n * m
+            }
+            (4, Reduction::S) => {
+                let [_, d2, _, h2, _] = self.off;
+                let m = f(k) * f(k + 2);
+                d2 * m + h2 * n * m
+            }
+            (6, Reduction::S) => {
+                let [_, _, _, _, h3] = self.off;
+                h3 * f(k) * f(k + 2) * f(k + 4)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// return the Wang submatrix for J = `j` containing the functions
+    /// (|J, K⟩ + `gamma` |J, -K⟩)/√2 with K ≥ 0 of the same parity as
+    /// `parity`, along with those values of K
+    fn wang_block(
+        &self,
+        j: usize,
+        parity: usize,
+        gamma: f64,
+    ) -> (Vec<Vec<f64>>, Vec<usize>) {
+        let ks: Vec<usize> = (parity..=j)
+            .step_by(2)
+            .filter(|&k| k > 0 || gamma > 0.0)
+            .collect();
+        let mat = ks
+            .iter()
+            .map(|&k1| {
+                ks.iter()
+                    .map(|&k2| {
+                        let (a, b) = (k1 as i64, k2 as i64);
+                        match (k1, k2) {
+                            (0, 0) => self.elem(j, 0, 0),
+                            (0, _) | (_, 0) => {
+                                std::f64::consts::SQRT_2 * self.elem(j, a, b)
+                            }
+                            _ => {
+                                self.elem(j, a, b) + gamma * self.elem(j, a, -b)
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        (mat, ks)
+    }
+}
+
+/// compute the eigenvalues of the symmetric matrix `a` with the cyclic Jacobi
+/// method, in ascending order
+fn eigenvalues(mut a: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = a.len();
+    for _ in 0..MAX_SWEEPS {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        let diag: f64 = (0..n).map(|i| a[i][i] * a[i][i]).sum();
+        if off <= 1e-30 * diag.max(f64::MIN_POSITIVE) {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (rp, rq) = (a[p].clone(), a[q].clone());
+                for (k, (vp, vq)) in rp.into_iter().zip(rq).enumerate() {
+                    a[p][k] = c * vp - s * vq;
+                    a[q][k] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+    let mut ret: Vec<_> = (0..n).map(|i| a[i][i]).collect();
+    ret.sort_by(|a, b| a.total_cmp(b));
+    ret
+}
+
+/// compute the rotational energy levels up to `jmax` by diagonalizing the
+/// `reduction` of Watson's Hamiltonian in representation `rep`, with the
+/// rotational constants in `rots` and the distortion constants in `deltas`
+/// and `phis`, all in MHz. missing distortion constants are treated as zero.
+/// returns `None` if `rots` does not contain three constants. the levels are
+/// sorted by J and then by energy
+pub fn energy_levels(
+    rots: &[f64],
+    deltas: &Delta,
+    phis: &Phi,
+    reduction: Reduction,
+    rep: Representation,
+    jmax: usize,
+) -> Option<Vec<Level>> {
+    let watson = Watson::new(rep.xyz(rots)?, deltas, phis, reduction);
+    let mut ret = Vec::new();
+    for j in 0..=jmax {
+        let mut levels = Vec::new();
+        for (parity, gamma) in [(0, 1.0), (0, -1.0), (1, 1.0), (1, -1.0)] {
+            let (mat, mut ks) = watson.wang_block(j, parity, gamma);
+            // within a block the energies increase with Ka, which increases
+            // with K in Ir and decreases with K in IIIr
+            if rep == Representation::IIIr {
+                ks.reverse();
+            }
+            // C₂ about the x axis takes |J, K⟩ to (-1)^J |J, -K⟩, so the
+            // character of this block, (-1)^(Ka + Kc) in Ir and (-1)^Ka in
+            // IIIr, picks between J - K and J - K + 1 for the other label
+            let minus = usize::from(gamma < 0.0);
+            for (energy, k) in eigenvalues(mat).into_iter().zip(ks) {
+                let (ka, kc) = match rep {
+                    Representation::Ir => (k, j - k + minus),
+                    Representation::IIIr => (j - k + (k + minus) % 2, k),
+                };
+                levels.push(Level { j, ka, kc, energy });
+            }
+        }
+        levels.sort_by(|a, b| a.energy.total_cmp(&b.energy));
+        ret.extend(levels);
+    }
+    Some(ret)
+}
+
+/// return the allowed a-, b-, and c-type transitions with ΔJ = 0 or ±1
+/// between the levels in `levels`, sorted by frequency
+pub fn transitions(levels: &[Level]) -> Vec<Transition> {
+    let mut ret = Vec::new();
+    for lower in levels {
+        for upper in levels {
+            if upper.energy <= lower.energy || upper.j.abs_diff(lower.j) > 1 {
+                continue;
+            }
+            let dka = (upper.ka + lower.ka) % 2 == 1;
+            let dkc = (upper.kc + lower.kc) % 2 == 1;
+            let dipole = match (dka, dkc) {
+                (false, true) => DipoleType::A,
+                (true, true) => DipoleType::B,
+                (true, false) => DipoleType::C,
+                (false, false) => continue,
+            };
+            ret.push(Transition {
+                upper: *upper,
+                lower: *lower,
+                freq: upper.energy - lower.energy,
+                dipole,
+            });
+        }
+    }
+    ret.sort_by(|a, b| a.freq.total_cmp(&b.freq));
+    ret
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// relative tolerance for treating two principal moments of inertia as equal
+pub const ROTOR_EPS: f64 = 1e-4;
+
+/// absolute tolerance in MHz for treating the rotational constants of a
+/// vibrational state that are equal at equilibrium as still equal
+pub const SPLIT_EPS: f64 = 0.1;
+
+/// the type of rotor a molecule is, determined by its principal moments of
+/// inertia. this also determines which rotational constants are stored in
+/// [crate::Summary::rots] and [crate::Summary::rot_equil]: B for linear
+/// molecules and spherical tops, A and B for prolate symmetric tops, B and C
+/// for oblate symmetric tops, and all three for asymmetric tops
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum RotorType {
+    /// Ia = 0, Ib = Ic
+    Linear,
+
+    /// Ia < Ib = Ic
+    Prolate,
+
+    /// Ia = Ib < Ic
+    Oblate,
+
+    /// Ia = Ib = Ic
+    Spherical,
+
+    /// Ia < Ib < Ic
+    #[default]
+    Asymmetric,
+}
+
+impl RotorType {
+    /// classify the principal moments of inertia in `moments`, which can be in
+    /// any order, treating moments within a relative tolerance of `eps` of
+    /// each other as equal
+    pub fn from_moments(moments: [f64; 3], eps: f64) -> Self {
+        let mut v = moments;
+        v.sort_by(|a, b| a.total_cmp(b));
+        let [ia, ib, ic] = v;
+        let close = |x: f64, y: f64| (x - y).abs() <= eps * ic;
+        if ic <= 0.0 || ia <= eps * ic {
+            Self::Linear
+        } else if close(ia, ib) && close(ib, ic) {
+            Self::Spherical
+        } else if close(ib, ic) {
+            Self::Prolate
+        } else if close(ia, ib) {
+            Self::Oblate
+        } else {
+            Self::Asymmetric
+        }
+    }
+
+    /// reduce the three rotational constants in `rots` to the unique constants
+    /// for this type of rotor, averaging the ones that should be equal. the
+    /// constants are sorted in descending order first, so their order in
+    /// `rots` does not matter. anything other than three constants is assumed
+    /// to be reduced already and is returned unchanged
+    pub fn unique_rots(&self, rots: &[f64]) -> Vec<f64> {
+        let [a, b, c] = rots else {
+            return rots.to_vec();
+        };
+        let mut v = [*a, *b, *c];
+        v.sort_by(|a, b| b.total_cmp(a));
+        let [a, b, c] = v;
+        match self {
+            // the smallest constant is zero or even infinite in some outputs,
+            // so just take the middle one
+            RotorType::Linear => vec![b],
+            RotorType::Prolate => vec![a, (b + c) / 2.0],
+            RotorType::Oblate => vec![(a + b) / 2.0, c],
+            RotorType::Spherical => vec![(a + b + c) / 3.0],
+            RotorType::Asymmetric => vec![a, b, c],
+        }
+    }
+
+    /// like [RotorType::unique_rots], but for the rotational constants of a
+    /// vibrational state. vibration-rotation interaction can split constants
+    /// that are equal at equilibrium, so all three are kept, in descending
+    /// order, if the ones that should be equal differ by more than
+    /// [SPLIT_EPS]
+    pub fn unique_state_rots(&self, rots: &[f64]) -> Vec<f64> {
+        let [a, b, c] = rots else {
+            return rots.to_vec();
+        };
+        let mut v = [*a, *b, *c];
+        v.sort_by(|a, b| b.total_cmp(a));
+        let split = match self {
+            RotorType::Prolate => v[1] - v[2],
+            RotorType::Oblate => v[0] - v[1],
+            RotorType::Spherical => v[0] - v[2],
+            RotorType::Linear | RotorType::Asymmetric => 0.0,
+        };
+        if split > SPLIT_EPS {
+            v.to_vec()
+        } else {
+            self.unique_rots(&v)
+        }
+    }
+
+    /// return the index into the unique rotational constants from
+    /// [RotorType::unique_rots] of the `j`th of the A, B, and C constants, or
+    /// `None` if that constant is not stored for this type of rotor
+    pub fn abc_index(&self, j: usize) -> Option<usize> {
+        match (self, j) {
+            (RotorType::Asymmetric, 0..=2) => Some(j),
+            (RotorType::Prolate, 0 | 1) => Some(j),
+            (RotorType::Oblate, 1 | 2) => Some(j - 1),
+            (RotorType::Linear | RotorType::Spherical, 1) => Some(0),
+            _ => None,
+        }
+    }
+
+    /// return the `j`th of the A, B, and C rotational constants in `rots`,
+    /// which should contain the unique constants for this type of rotor or
+    /// all three constants of a split state from
+    /// [RotorType::unique_state_rots]
+    pub fn abc<'a>(&self, rots: &'a [f64], j: usize) -> Option<&'a f64> {
+        if self.is_split(rots) {
+            return rots.get(j);
+        }
+        self.abc_index(j).and_then(|i| rots.get(i))
+    }
+
+    /// return all three of the A, B, and C rotational constants in `rots`,
+    /// repeating the ones that are equal for this type of rotor. A is `None`
+    /// for linear molecules
+    pub fn expand(&self, rots: &[f64]) -> [Option<f64>; 3] {
+        let get = |i: usize| rots.get(i).copied();
+        if self.is_split(rots) {
+            return [get(0), get(1), get(2)];
+        }
+        match self {
+            RotorType::Linear => [None, get(0), get(0)],
+            RotorType::Prolate => [get(0), get(1), get(1)],
+            RotorType::Oblate => [get(0), get(0), get(1)],
+            RotorType::Spherical => [get(0), get(0), get(0)],
+            RotorType::Asymmetric => [get(0), get(1), get(2)],
+        }
+    }
+    /// report whether `rots` contains all three rotational constants of a
+    /// symmetric or spherical top state that
+    /// [RotorType::unique_state_rots] kept split
+    pub fn is_split(&self, rots: &[f64]) -> bool {
+        !matches!(self, RotorType::Linear | RotorType::Asymmetric)
+            && rots.len() == 3
+    }
+}
@@ -0,0 +1,480 @@
+//! semi-experimental equilibrium structures, fit to the semi-experimental
+//! equilibrium rotational constants Be(SE) = B0(exp) + Be(calc) − B0(calc) of
+//! several isotopologues
+
+use std::path::Path;
+
+use symm::Molecule;
+
+use crate::{curvil::Curvil, inertia, Summary};
+
+/// maximum number of iterations for both the least-squares fit and the
+/// conversion of internal coordinates to Cartesian coordinates
+const MAX_ITER: usize = 50;
+
+/// convergence threshold on the largest step in the fitted parameters
+const FIT_CONV: f64 = 1e-8;
+
+/// convergence threshold on the largest error in the internal coordinates
+/// when converting them to Cartesian coordinates
+const GEOM_CONV: f64 = 1e-10;
+
+/// coordinates of the same kind whose initial values differ by less than this
+/// are treated as symmetry-equivalent and fit as a single parameter
+const EQUIV_EPS: f64 = 1e-5;
+
+/// step size for the numerical derivatives of the internal coordinates with
+/// respect to the Cartesian coordinates, in Å
+const CART_STEP: f64 = 1e-6;
+
+/// step size for the numerical derivatives of the rotational constants with
+/// respect to the fitted parameters, in Å or degrees
+const PARAM_STEP: f64 = 1e-4;
+
+/// experimental ground state rotational constants of one isotopologue and
+/// their uncertainties, both in MHz
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpRots {
+    pub rots: Vec<f64>,
+    pub sigma: Vec<f64>,
+}
+
+/// load experimental ground state rotational constants from the reference file
+/// at `path`, which should contain one line for each of the `summaries` in
+/// the same order. each line contains the unique rotational constants in MHz
+/// in the same order as [Summary::rots], optionally followed by their
+/// uncertainties, which default to 1 MHz. blank lines and lines starting with
+/// `#` are ignored
+pub fn load_reference<P>(
+    path: P,
+    summaries: &[Summary],
+) -> Result<Vec<ExpRots>, String>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let data = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let lines: Vec<_> = data
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect();
+    if lines.len() != summaries.len() {
+        return Err(format!(
+            "found {} reference lines for {} isotopologues",
+            lines.len(),
+            summaries.len()
+        ));
+    }
+    let mut ret = Vec::new();
+    for (i, (line, sum)) in lines.into_iter().zip(summaries).enumerate() {
+        let vals = line
+            .split_whitespace()
+            .map(|s| s.parse::<f64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("failed to parse reference line {i}: {e}"))?;
+        let n = sum.rot_equil.len();
+        let (rots, sigma) = if vals.len() == n {
+            (vals, vec![1.0; n])
+        } else if vals.len() == 2 * n {
+            let sigma = vals[n..].to_vec();
+            (vals[..n].to_vec(), sigma)
+        } else {
+            return Err(format!(
+                "expected {n} or {} values on reference line {i}, found {}",
+                2 * n,
+                vals.len()
+            ));
+        };
+        ret.push(ExpRots { rots, sigma });
+    }
+    Ok(ret)
+}
+
+/// a fitted structural parameter
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    /// indices into the [Summary::curvils] of the parent molecule of the
+    /// symmetry-equivalent coordinates described by this parameter
+    pub curvils: Vec<usize>,
+
+    /// the value of the parameter in the parent's geometry, in Å or degrees
+    pub initial: f64,
+
+    /// the fitted value of the parameter
+    pub value: f64,
+
+    /// the standard deviation of the fitted value
+    pub std_dev: f64,
+}
+
+/// the result of a semi-experimental equilibrium structure fit
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeFit {
+    /// the fitted parameters
+    pub params: Vec<Param>,
+
+    /// the semi-experimental equilibrium rotational constants of each
+    /// isotopologue in MHz
+    pub be_se: Vec<Vec<f64>>,
+
+    /// the equilibrium rotational constants of each isotopologue in MHz,
+    /// computed from the fitted geometry
+    pub be_fit: Vec<Vec<f64>>,
+
+    /// the weighted standard deviation of the fit
+    pub std_dev: f64,
+
+    /// the number of least-squares iterations taken
+    pub iterations: usize,
+
+    /// whether the largest parameter step fell below the convergence
+    /// threshold before the iteration limit. the other fields are from the
+    /// last iteration either way
+    pub converged: bool,
+}
+
+impl SeFit {
+    /// return the residuals Be(SE) − Be(fit) in MHz for each isotopologue
+    pub fn residuals(&self) -> Vec<Vec<f64>> {
+        self.be_se
+            .iter()
+            .zip(&self.be_fit)
+            .map(|(se, fit)| se.iter().zip(fit).map(|(s, f)| s - f).collect())
+            .collect()
+    }
+}
+
+/// the rigid-rotor model of the isotopologues being fit
+struct Model<'a> {
+    /// the parent geometry, used for the atomic numbers
+    geom: &'a Molecule,
+
+    /// the coordinates being fit, as pairs of the index of their parameter
+    /// and the coordinate itself
+    coords: Vec<(usize, &'a Curvil)>,
+
+    /// the isotopologues being fit
+    summaries: &'a [Summary],
+}
+
+impl Model<'_> {
+    /// return the rotational constants of every isotopologue for the geometry
+    /// `x`, in the same order as the data
+    fn rots(&self, x: &[[f64; 3]]) -> Vec<Vec<f64>> {
+        let mut geom = self.geom.clone();
+        for (atom, r) in geom.atoms.iter_mut().zip(x) {
+            atom.x = r[0];
+            atom.y = r[1];
+            atom.z = r[2];
+        }
+        self.summaries
+            .iter()
+            .map(|sum| {
+                let moments = inertia::principal_moments(&geom, &sum.masses);
+                sum.rotor.unique_rots(&inertia::rot_consts(moments))
+            })
+            .collect()
+    }
+
+    /// update `x` to the geometry with the fitted coordinates set to the
+    /// values in `params`, taking the smallest step in the Cartesian
+    /// coordinates that does so
+    fn geometry(
+        &self,
+        x: &mut [[f64; 3]],
+        params: &[f64],
+    ) -> Result<(), String> {
+        for _ in 0..MAX_ITER {
+            let dq: Vec<_> = self
+                .coords
+                .iter()
+                .zip(self.values(x))
+                .map(|((p, _), v)| params[*p] - v)
+                .collect();
+            if dq.iter().all(|d| d.abs() < GEOM_CONV) {
+                return Ok(());
+            }
+            let b = self.bmat(x);
+            // minimum-norm step dx = Bᵀ (B Bᵀ)⁻¹ dq
+            let bbt: Vec<Vec<f64>> = b
+                .iter()
+                .map(|r| b.iter().map(|s| dot(r, s)).collect())
+                .collect();
+            let y = solve(bbt, dq).ok_or_else(|| {
+                String::from("fitted coordinates are not independent")
+            })?;
+            for (k, r) in x.iter_mut().enumerate() {
+                for (l, v) in r.iter_mut().enumerate() {
+                    *v += zip_sum(&y, b.iter().map(|row| row[3 * k + l]));
+                }
+            }
+        }
+        Err(String::from("failed to convert coordinates to a geometry"))
+    }
+
+    /// compute the Wilson B matrix of the fitted coordinates at the geometry
+    /// `x` by central differences
+    fn bmat(&self, x: &[[f64; 3]]) -> Vec<Vec<f64>> {
+        let mut x = x.to_vec();
+        let mut ret = vec![vec![0.0; 3 * x.len()]; self.coords.len()];
+        for k in 0..x.len() {
+            for l in 0..3 {
+                let orig = x[k][l];
+                x[k][l] = orig + CART_STEP;
+                let plus: Vec<_> = self.values(&x);
+                x[k][l] = orig - CART_STEP;
+                let minus: Vec<_> = self.values(&x);
+                x[k][l] = orig;
+                for (row, (p, m)) in ret.iter_mut().zip(plus.iter().zip(minus))
+                {
+                    row[3 * k + l] = (p - m) / (2.0 * CART_STEP);
+                }
+            }
+        }
+        ret
+    }
+
+    /// return the values of the fitted coordinates at the geometry `x`
+    fn values(&self, x: &[[f64; 3]]) -> Vec<f64> {
+        self.coords
+            .iter()
+            .map(|(_, c)| coord_value(c, x).unwrap())
+            .collect()
+    }
+}
+
+/// return Σ aᵢbᵢ
+fn zip_sum(a: &[f64], b: impl Iterator<Item = f64>) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    zip_sum(a, b.iter().copied())
+}
+
+/// return the value of `curvil` at the geometry `x` in Å or degrees, or `None`
+/// if it's not a bond or angle. following SPECTRO, the first atom of an angle
+/// is its vertex
+fn coord_value(curvil: &Curvil, x: &[[f64; 3]]) -> Option<f64> {
+    let sub = |a: usize, b: usize| {
+        let (a, b) = (x[a - 1], x[b - 1]);
+        [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+    };
+    match curvil {
+        Curvil::Bond(a, b) => Some(dot(&sub(*a, *b), &sub(*a, *b)).sqrt()),
+        Curvil::Angle(a, b, c) => {
+            let (u, v) = (sub(*b, *a), sub(*c, *a));
+            let cos = dot(&u, &v) / (dot(&u, &u) * dot(&v, &v)).sqrt();
+            Some(cos.clamp(-1.0, 1.0).acos().to_degrees())
+        }
+        _ => None,
+    }
+}
+
+/// solve the linear system `a`x = `b` by Gaussian elimination with partial
+/// pivoting, returning `None` if `a` is singular
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    let scale = a
+        .iter()
+        .flatten()
+        .fold(0.0_f64, |m, v| m.max(v.abs()))
+        .max(f64::MIN_POSITIVE);
+    for col in 0..n {
+        let piv = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[piv][col].abs() < 1e-12 * scale {
+            return None;
+        }
+        a.swap(col, piv);
+        b.swap(col, piv);
+        for row in col + 1..n {
+            let f = a[row][col] / a[col][col];
+            let pivot_row = a[col].clone();
+            for (v, p) in a[row].iter_mut().zip(pivot_row).skip(col) {
+                *v -= f * p;
+            }
+            b[row] -= f * b[col];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let s = zip_sum(&a[row][row + 1..], x[row + 1..].iter().copied());
+        x[row] = (b[row] - s) / a[row][row];
+    }
+    Some(x)
+}
+
+/// fit the bond and angle coordinates in the [Summary::curvils] of the first
+/// of `summaries` to the semi-experimental equilibrium rotational constants of
+/// every isotopologue in `summaries`, formed by adding their computed
+/// vibrational corrections Be − B0 to the experimental constants in `exp`.
+/// every summary should describe the same equilibrium geometry, with the
+/// atoms in the same order, and the masses of its isotopologue. coordinates
+/// with equal initial values are assumed to be equivalent by symmetry and fit
+/// together, while the other kinds of coordinates are left free
+pub fn fit(summaries: &[Summary], exp: &[ExpRots]) -> Result<SeFit, String> {
+    let Some(parent) = summaries.first() else {
+        return Err(String::from("no isotopologues to fit"));
+    };
+    if exp.len() != summaries.len() {
+        return Err(format!(
+            "found {} sets of experimental constants for {} isotopologues",
+            exp.len(),
+            summaries.len()
+        ));
+    }
+    let natoms = parent.geom.atoms.len();
+
+    // form the semi-experimental constants and weights
+    let mut be_se = Vec::new();
+    let mut weights = Vec::new();
+    for (i, (sum, e)) in summaries.iter().zip(exp).enumerate() {
+        if sum.masses.len() != natoms {
+            return Err(format!("wrong number of atoms in isotopologue {i}"));
+        }
+        let b0 = sum.rots.first().map_or(&[][..], |r| r.as_slice());
+        let n = sum.rot_equil.len();
+        if n == 0 || b0.len() != n || e.rots.len() != n || e.sigma.len() != n {
+            return Err(format!(
+                "mismatched rotational constants for isotopologue {i}"
+            ));
+        }
+        be_se.push(
+            (0..n)
+                .map(|j| e.rots[j] + sum.rot_equil[j] - b0[j])
+                .collect::<Vec<_>>(),
+        );
+        weights.extend(e.sigma.iter().map(|s| 1.0 / (s * s)));
+    }
+    let y: Vec<f64> = be_se.iter().flatten().copied().collect();
+
+    // group the coordinates into parameters
+    let x0: Vec<[f64; 3]> =
+        parent.geom.atoms.iter().map(|a| [a.x, a.y, a.z]).collect();
+    let mut coords = Vec::new();
+    let mut params: Vec<Param> = Vec::new();
+    for (i, curvil) in parent.curvils.iter().enumerate() {
+        let Some(v) = coord_value(curvil, &x0) else {
+            continue;
+        };
+        let same_kind = |p: &Param| {
+            std::mem::discriminant(&parent.curvils[p.curvils[0]])
+                == std::mem::discriminant(curvil)
+        };
+        let found = params
+            .iter()
+            .position(|p| same_kind(p) && (p.initial - v).abs() < EQUIV_EPS);
+        let idx = match found {
+            Some(idx) => idx,
+            None => {
+                params.push(Param {
+                    curvils: Vec::new(),
+                    initial: v,
+                    value: v,
+                    std_dev: 0.0,
+                });
+                params.len() - 1
+            }
+        };
+        params[idx].curvils.push(i);
+        coords.push((idx, curvil));
+    }
+    let np = params.len();
+    if np == 0 {
+        return Err(String::from("no bond or angle coordinates to fit"));
+    }
+    if y.len() < np {
+        return Err(format!(
+            "{} rotational constants are not enough to fit {np} parameters",
+            y.len()
+        ));
+    }
+
+    let model = Model {
+        geom: &parent.geom,
+        coords,
+        summaries,
+    };
+    let mut p: Vec<f64> = params.iter().map(|p| p.value).collect();
+    let mut x = x0;
+    let mut iterations = 0;
+    let mut normal;
+    let converged = loop {
+        iterations += 1;
+        model.geometry(&mut x, &p)?;
+        let f: Vec<f64> = model.rots(&x).into_iter().flatten().collect();
+        let r: Vec<f64> = y.iter().zip(&f).map(|(y, f)| y - f).collect();
+
+        // jacobian of the rotational constants by central differences
+        let mut jac = vec![vec![0.0; np]; y.len()];
+        for k in 0..np {
+            let mut xs = x.clone();
+            let mut q = p.clone();
+            q[k] = p[k] + PARAM_STEP;
+            model.geometry(&mut xs, &q)?;
+            let plus: Vec<f64> =
+                model.rots(&xs).into_iter().flatten().collect();
+            q[k] = p[k] - PARAM_STEP;
+            model.geometry(&mut xs, &q)?;
+            let minus: Vec<f64> =
+                model.rots(&xs).into_iter().flatten().collect();
+            for (row, (a, b)) in jac.iter_mut().zip(plus.iter().zip(minus)) {
+                row[k] = (a - b) / (2.0 * PARAM_STEP);
+            }
+        }
+
+        // normal equations JᵀWJ Δ = JᵀWr
+        normal = vec![vec![0.0; np]; np];
+        let mut rhs = vec![0.0; np];
+        for ((row, w), r) in jac.iter().zip(&weights).zip(&r) {
+            for (a, (n, ra)) in row.iter().zip(normal.iter_mut().zip(&mut rhs))
+            {
+                *ra += a * w * r;
+                for (b, v) in row.iter().zip(n.iter_mut()) {
+                    *v += a * w * b;
+                }
+            }
+        }
+        let step = solve(normal.clone(), rhs).ok_or_else(|| {
+            String::from("the parameters are not determined by the data")
+        })?;
+        for (p, s) in p.iter_mut().zip(&step) {
+            *p += s;
+        }
+        let done = step.iter().all(|s| s.abs() < FIT_CONV);
+        if done || iterations >= MAX_ITER {
+            break done;
+        }
+    };
+    model.geometry(&mut x, &p)?;
+    let be_fit = model.rots(&x);
+
+    let chi2: f64 = be_se
+        .iter()
+        .flatten()
+        .zip(be_fit.iter().flatten())
+        .zip(&weights)
+        .map(|((s, f), w)| w * (s - f).powi(2))
+        .sum();
+    let dof = y.len() - np;
+    let var = if dof > 0 { chi2 / dof as f64 } else { 1.0 };
+    for (k, (param, v)) in params.iter_mut().zip(p).enumerate() {
+        let mut unit = vec![0.0; np];
+        unit[k] = 1.0;
+        let col = solve(normal.clone(), unit).unwrap_or_else(|| vec![0.0; np]);
+        param.value = v;
+        param.std_dev = (var * col[k]).sqrt();
+    }
+
+    Ok(SeFit {
+        params,
+        be_se,
+        be_fit,
+        std_dev: var.sqrt(),
+        iterations,
+        converged,
+    })
+}
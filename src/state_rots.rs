@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+/// vibrationally-averaged rotational constants for every vibrational state
+/// SPECTRO analyzed, including overtones and combination states
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct StateRots {
+    /// map of the quanta in each mode, in the same order as
+    /// [crate::vib_state::VibState::quanta], to the rotational constants of
+    /// that state in MHz. like [crate::Summary::rots], only the unique
+    /// constants for the [crate::rotor::RotorType] of the molecule are stored
+    pub data: HashMap<Vec<usize>, Vec<f64>>,
+}
+
+impl StateRots {
+    /// return the rotational constants for the state with `quanta`
+    pub fn get(&self, quanta: &[usize]) -> Option<&Vec<f64>> {
+        self.data.get(quanta)
+    }
+
+    /// return the keys of `self`, sorted first by the total number of quanta
+    /// and then in descending order so that ν1 comes before ν2
+    pub fn keys(&self) -> Vec<&Vec<usize>> {
+        let mut keys: Vec<_> = self.data.keys().collect();
+        keys.sort_unstable_by(|a, b| {
+            let (sa, sb): (usize, usize) = (a.iter().sum(), b.iter().sum());
+            sa.cmp(&sb).then(b.cmp(a))
+        });
+        keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// simpler representation for almost trivially implementing Serialize and
+/// Deserialize
+#[derive(Deserialize)]
+struct DummyStateRots {
+    states: Vec<Vec<usize>>,
+    rots: Vec<Vec<f64>>,
+}
+
+impl From<DummyStateRots> for StateRots {
+    fn from(value: DummyStateRots) -> Self {
+        Self {
+            data: value.states.into_iter().zip(value.rots).collect(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StateRots {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = DummyStateRots::deserialize(deserializer)?;
+        Ok(s.into())
+    }
+}
+
+impl Serialize for StateRots {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("StateRots", 2)?;
+        let mut states = Vec::new();
+        let mut rots = Vec::new();
+        for key in self.keys() {
+            states.push(key);
+            rots.push(&self.data[key]);
+        }
+        s.serialize_field("states", &states)?;
+        s.serialize_field("rots", &rots)?;
+        s.end()
+    }
+}
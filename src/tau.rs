@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// the planar quartic centrifugal distortion constants τ′ in the principal
+/// axis system, in MHz
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TauPrime {
+    pub aaaa: Option<f64>,
+    pub bbbb: Option<f64>,
+    pub cccc: Option<f64>,
+    pub aabb: Option<f64>,
+    pub aacc: Option<f64>,
+    pub bbcc: Option<f64>,
+}
+
+impl Display for TauPrime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_dist_consts!(f, self,
+        aaaa => "TAUP aaaa",
+        bbbb => "TAUP bbbb",
+        cccc => "TAUP cccc",
+        aabb => "TAUP aabb",
+        aacc => "TAUP aacc",
+        bbcc => "TAUP bbcc",
+           );
+
+        Ok(())
+    }
+}
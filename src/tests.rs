@@ -22,6 +22,104 @@ fn load_mat(filename: &str) -> Vec<Vec<f64>> {
     ret
 }
 
+/// load a [ForceConstants] from `filename`, where each line contains the
+/// coordinate indices followed by the value, or `*` for a missing value
+fn load_fcs(filename: &str) -> ForceConstants {
+    let data = read_to_string(filename).unwrap();
+    let mut ret = ForceConstants::default();
+    for line in data.lines() {
+        let mut fields: Vec<_> = line.split_ascii_whitespace().collect();
+        let Some(v) = fields.pop() else {
+            continue;
+        };
+        let key = fields.iter().map(|s| s.parse().unwrap()).collect();
+        ret.data.insert(key, v.parse().ok());
+    }
+    ret
+}
+
+/// load a sequence of [VibState]s from `filename`, where each line contains
+/// the energy relative to the ZPT, the total energy, and then the quanta
+fn load_states(filename: &str) -> Vec<VibState> {
+    let data = read_to_string(filename).unwrap();
+    let mut ret = Vec::new();
+    for line in data.lines() {
+        let fields: Vec<_> = line.split_ascii_whitespace().collect();
+        if fields.len() < 2 {
+            continue;
+        }
+        ret.push(VibState {
+            zpt_rel: fields[0].parse().unwrap(),
+            energy: fields[1].parse().unwrap(),
+            quanta: fields[2..].iter().map(|s| s.parse().unwrap()).collect(),
+        });
+    }
+    ret
+}
+
+/// load a [StateRots] from `filename`, where each line contains the quanta,
+/// a colon, and then the rotational constants
+fn load_rots(filename: &str) -> StateRots {
+    let data = read_to_string(filename).unwrap();
+    let mut ret = StateRots::default();
+    for line in data.lines() {
+        let Some((quanta, rots)) = line.split_once(':') else {
+            continue;
+        };
+        ret.data.insert(
+            quanta
+                .split_ascii_whitespace()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+            rots.split_ascii_whitespace()
+                .map(|s| s.parse().unwrap())
+                .collect(),
+        );
+    }
+    ret
+}
+
+/// load a sequence of [RotEnergies] from `filename`. each one starts with a
+/// line containing the reduction, the quanta, a colon, and then the
+/// rotational constants in cm⁻¹, followed by lines containing J, Ka, Kc, and
+/// the energy in cm⁻¹
+fn load_energies(filename: &str) -> Vec<RotEnergies> {
+    let data = read_to_string(filename).unwrap();
+    let mut ret: Vec<RotEnergies> = Vec::new();
+    for line in data.lines() {
+        if let Some((head, rots)) = line.split_once(':') {
+            let mut fields = head.split_ascii_whitespace();
+            let reduction = match fields.next() {
+                Some("A") => Reduction::A,
+                Some("S") => Reduction::S,
+                r => panic!("unrecognized reduction {r:?}"),
+            };
+            let mut rots: Vec<f64> = rots
+                .split_ascii_whitespace()
+                .map(|s| s.parse::<f64>().unwrap() * TO_MHZ)
+                .collect();
+            rots.sort_by(|a, b| b.total_cmp(a));
+            ret.push(RotEnergies {
+                quanta: fields.map(|s| s.parse().unwrap()).collect(),
+                reduction,
+                rots,
+                levels: Vec::new(),
+            });
+            continue;
+        }
+        let fields: Vec<_> = line.split_ascii_whitespace().collect();
+        if let [j, ka, kc, energy] = fields[..] {
+            ret.last_mut().unwrap().levels.push(Level {
+                j: j.parse().unwrap(),
+                ka: ka.parse().unwrap(),
+                kc: kc.parse().unwrap(),
+                energy: energy.parse::<f64>().unwrap() * TO_MHZ,
+            });
+        }
+    }
+    ret
+}
+
 macro_rules! check {
     ($got:expr, $want:expr) => {
         assert_eq!($got.harm.len(), $want.harm.len());
@@ -33,17 +131,38 @@ macro_rules! check {
         assert_eq!($got.lxm.len(), $want.lxm.len());
         assert_eq!($got.lxm, $want.lxm);
         assert_eq!($got.zpt, $want.zpt);
+        assert_eq!($got.masses, $want.masses);
+        assert_eq!($got.rotor, $want.rotor);
         assert_eq!($got.irreps, $want.irreps);
         assert_eq!($got.rots.len(), $want.rots.len());
         assert_eq!($got.rots, $want.rots);
         assert_eq!($got.rot_equil, $want.rot_equil);
         assert_eq!($got.deltas, $want.deltas);
         assert_eq!($got.phis, $want.phis);
+        assert_eq!($got.derived_reduction, $want.derived_reduction);
+        assert_eq!($got.isotopologue, $want.isotopologue);
+        assert_eq!($got.nielsen, $want.nielsen);
+        assert_eq!($got.wilson, $want.wilson);
+        assert_eq!($got.tau_prime, $want.tau_prime);
         assert_eq!($got.fermi, $want.fermi,);
         assert_eq!($got.coriolis, $want.coriolis);
+        assert_eq!($got.darling_dennison, $want.darling_dennison);
         assert_eq!($got.curvils, $want.curvils);
         assert_eq!($got.ralpha, $want.ralpha);
         assert_eq!($got.requil, $want.requil);
+        assert_eq!($got.rg, $want.rg);
+        assert_eq!($got.dist, $want.dist);
+        assert_eq!($got.xmat, $want.xmat);
+        assert_eq!($got.alphas, $want.alphas);
+        assert_eq!($got.polyads, $want.polyads);
+        assert_eq!($got.internal_fcs, $want.internal_fcs);
+        assert_eq!($got.msa, $want.msa);
+        assert_eq!($got.ped, $want.ped);
+        assert_eq!($got.zeta, $want.zeta);
+        assert_eq!($got.vib_states, $want.vib_states);
+        assert_eq!($got.state_rots, $want.state_rots);
+        assert_eq!($got.perturbed_defects, $want.perturbed_defects);
+        assert_eq!($got.rot_energies, $want.rot_energies);
         assert_eq!($got, $want);
     };
 }
@@ -65,6 +184,8 @@ fn c3h2() {
             876.8004, 876.4785, 772.6584,
         ],
         zpt: 6993.7720,
+        masses: vec![12.0, 12.0, 12.0, 1.007825, 1.007825],
+        rotor: RotorType::Asymmetric,
         irreps: vec![A1, B2, A1, A1, B2, A2, B2, A1, B1],
         geom: Molecule::from_str(
             "
@@ -120,6 +241,29 @@ H                  1.5951933      0.9069249      0.0000000
             h3: Some(4.148099836e-9),
             he: None,
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen {
+            d_j: Some(0.0334048859),
+            d_jk: Some(0.0870035664),
+            d_k: Some(0.0232616755),
+            delta_j: Some(0.01583603),
+            r5: Some(-0.0184912062),
+            r6: Some(-0.003543783),
+        },
+        wilson: Wilson {
+            d_j: Some(0.0088203919),
+            d_jk: Some(0.0066435923),
+            d_k: Some(0.0567005277),
+        },
+        tau_prime: TauPrime {
+            aaaa: Some(-0.574680511),
+            bbbb: Some(-0.2886580475),
+            cccc: Some(-0.0352815675),
+            aabb: Some(-0.5189004461),
+            aacc: Some(-0.0963529066),
+            bbcc: Some(-0.0485687521),
+        },
         fermi: HashMap::from([
             (3, vec![(7, 7), (8, 8), (9, 9)]),
             (1, vec![(3, 3)]),
@@ -154,6 +298,107 @@ H                  1.5951933      0.9069249      0.0000000
             1.3253932, 1.421078, 1.421078, 1.0769014, 1.0769014, 147.810114,
             147.810114,
         ],
+        rg: vec![
+            1.3328469,
+            1.4293289,
+            1.4293289,
+            1.0962242,
+            1.0962242,
+            146.8296161,
+            146.8296161,
+        ],
+        dist: load_mat("testfiles/c3h2.dist"),
+        xmat: load_mat("testfiles/c3h2.xmat"),
+        alphas: vec![
+            vec![57.910399464681404, 132.0098015160834, 48.97662318930094],
+            vec![57.19862921297322, 123.49681093682715, 45.715088103056964],
+            vec![-8.157787481244101, 184.72034276769892, 43.151883572629025],
+            vec![93.4318634668587, -10.8616606288148, -10.83267969189994],
+            vec![133.3403853720477, -37.85632860904496, 135.19952431693807],
+            vec![-23.087580800401042, 111.89735807094978, -15.338107649028359],
+            vec![49.36639535564506, -88.36787719160759, 58.22719615089916],
+            vec![-55.055173096764825, -71.80591180166293, 15.141075051857023],
+            vec![132.13603812429804, 7.83257562073028, -23.66781411323714],
+        ],
+        polyads: vec![
+            ResonancePolyad {
+                states: vec![vec![(3, 2)], vec![(1, 1)]],
+                energy: vec![vec![3174.269, 21.260], vec![21.260, 3152.935]],
+                eigenvalues: vec![3139.82, 3187.39],
+                eigenvectors: vec![
+                    vec![-0.5251440, -0.8510134],
+                    vec![0.8510134, -0.5251440],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(7, 2)], vec![(3, 1)]],
+                energy: vec![vec![1751.467, 12.601], vec![12.601, 1593.567]],
+                eigenvalues: vec![1592.57, 1752.47],
+                eigenvectors: vec![
+                    vec![-0.0790571, -0.9968701],
+                    vec![0.9968701, -0.0790571],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(8, 2)], vec![(3, 1)]],
+                energy: vec![vec![1749.234, 3.580], vec![3.580, 1592.568]],
+                eigenvalues: vec![1592.49, 1749.32],
+                eigenvectors: vec![
+                    vec![-0.0228351, -0.9997392],
+                    vec![0.9997392, -0.0228351],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(9, 2)], vec![(3, 1)]],
+                energy: vec![vec![1545.981, -11.383], vec![-11.383, 1592.486]],
+                eigenvalues: vec![1543.34, 1595.12],
+                eigenvectors: vec![
+                    vec![-0.9742076, -0.2256538],
+                    vec![-0.2256538, 0.9742076],
+                ],
+            },
+        ],
+        ped: Ped {
+            freqs: vec![
+                3281.36, 3247.65, 1623.59, 1307.44, 1090.56, 992.80, 908.65,
+                901.70, 785.14,
+            ],
+            data: load_mat("testfiles/c3h2.ped"),
+        },
+        internal_fcs: load_fcs("testfiles/c3h2.fcs"),
+        perturbed_defects: vec![
+            (vec![1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.0850111)),
+            (vec![0, 0, 2, 0, 0, 0, 0, 0, 0], Some(-0.076862)),
+            (vec![0, 0, 1, 0, 0, 0, 0, 0, 0], Some(-0.082724)),
+            (vec![0, 0, 0, 0, 0, 0, 2, 0, 0], Some(-0.3423054)),
+            (vec![0, 0, 1, 0, 0, 0, 0, 0, 0], Some(-0.081174)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 2, 0], Some(-0.2592744)),
+            (vec![0, 0, 1, 0, 0, 0, 0, 0, 0], Some(-0.0713373)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 2], Some(0.1005307)),
+        ],
+        rot_energies: load_energies("testfiles/c3h2.rotlev"),
+        state_rots: load_rots("testfiles/c3h2.rots"),
+        vib_states: load_states("testfiles/c3h2.states"),
+        zeta: [
+            load_mat("testfiles/c3h2.zetax"),
+            load_mat("testfiles/c3h2.zetay"),
+            load_mat("testfiles/c3h2.zetaz"),
+        ],
+        msa: HashMap::from([
+            (30, load_mat("testfiles/c3h2.msa30")),
+            (300, load_mat("testfiles/c3h2.msa300")),
+        ]),
+        darling_dennison: DarlingDennison {
+            data: HashMap::from([
+                ((2, 1), Some(-118.875)),
+                ((6, 5), Some(-10.042)),
+                ((7, 6), Some(-13.047)),
+                ((8, 6), Some(-24.179)),
+                ((8, 7), Some(-10.178)),
+                ((9, 7), Some(-34.525)),
+                ((9, 8), Some(-9.280)),
+            ]),
+        },
     };
     check!(got, want);
 }
@@ -175,6 +420,8 @@ fn c2h4() {
             1341.7506, 1226.4540, 1024.3674, 948.6771, 939.3649, 823.8796,
         ],
         zpt: 11022.5891,
+        masses: vec![12.0, 1.007825, 1.007825, 12.0, 1.007825, 1.007825],
+        rotor: RotorType::Asymmetric,
         irreps: vec![B2u, B3g, Ag, B1u, Ag, B1u, Ag, B3g, Au, B3u, B2g, B2u],
         geom: Molecule::from_str(
             "
@@ -234,6 +481,29 @@ fn c2h4() {
             h3: Some(5.1134704169999995e-9),
             he: None,
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen {
+            d_j: Some(0.0421316796),
+            d_jk: Some(0.3113128762),
+            d_k: Some(2.4586262639),
+            delta_j: Some(0.0081815174),
+            r5: Some(-0.0860072828),
+            r6: Some(-0.0006128922),
+        },
+        wilson: Wilson {
+            d_j: Some(0.0269944291),
+            d_jk: Some(0.0229197946),
+            d_k: Some(0.0098062751),
+        },
+        tau_prime: TauPrime {
+            aaaa: Some(-11.2482832786),
+            bbbb: Some(-0.2388819957),
+            cccc: Some(-0.1079777166),
+            aabb: Some(-1.5119368031),
+            aacc: Some(-0.0703681387),
+            bbcc: Some(-0.1538173059),
+        },
         fermi: HashMap::from([
             (3, vec![(5, 5), (5, 5), (7, 5)]),
             (5, vec![(12, 12), (12, 12)]),
@@ -279,6 +549,118 @@ fn c2h4() {
             31.4188659,
             32.6298714,
         ],
+        rg: vec![
+            1.1028187,
+            1.3413126,
+            2.4839389,
+            3.1017671,
+            1.1028187,
+            95.3597564,
+            146.4995492,
+            31.7353137,
+            32.6583952,
+        ],
+        dist: load_mat("testfiles/c2h4.dist"),
+        xmat: load_mat("testfiles/c2h4.xmat"),
+        alphas: vec![
+            vec![753.8005307514674, 68.14140768507367, 59.385066573885084],
+            vec![791.2413979784421, 63.25529127307852, 49.37484650503608],
+            vec![1257.7248212244765, 44.26182917327906, 65.6477130339576],
+            vec![1046.5353316657984, 36.03001094245644, 58.831364893581984],
+            vec![-401.617922697641, 84.66162097939267, 144.06485497623254],
+            vec![-1929.739505139539, -190.93014780912438, 47.205329430453745],
+            vec![-346.49557510481213, -25.263108713766282, 62.47992904517938],
+            vec![-4099.130472024421, -46.11043041327072, 63.769369384207764],
+            vec![2690.313915431782, -356.5106757948774, -0.23661119747649997],
+            vec![1048.8695007541635, 165.9419128042491, -40.439256485284716],
+            vec![3813.480387463018, 166.46328186795694, -20.620993383337343],
+            vec![-1492.426679508994, 447.1385774041375, 47.221533212808644],
+        ],
+        polyads: vec![
+            ResonancePolyad {
+                states: vec![vec![(5, 2)], vec![(3, 1)]],
+                energy: vec![vec![3247.006, -36.516], vec![-36.516, 3018.494]],
+                eigenvalues: vec![3012.80, 3252.70],
+                eigenvectors: vec![
+                    vec![0.1540522, -0.9880627],
+                    vec![0.9880627, 0.1540522],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(5, 1), (6, 1)], vec![(4, 1)]],
+                energy: vec![vec![3065.496, -44.337], vec![-44.337, 3000.770]],
+                eigenvalues: vec![2978.24, 3088.03],
+                eigenvectors: vec![
+                    vec![0.4530063, -0.8915073],
+                    vec![0.8915073, 0.4530063],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(5, 1), (7, 1)], vec![(3, 1)]],
+                energy: vec![vec![2962.013, 12.627], vec![12.627, 3012.801]],
+                eigenvalues: vec![2959.05, 3015.77],
+                eigenvectors: vec![
+                    vec![-0.9734997, 0.2286881],
+                    vec![0.2286881, 0.9734997],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(12, 2)], vec![(5, 1)]],
+                energy: vec![vec![1656.903, -13.355], vec![-13.355, 1628.282]],
+                eigenvalues: vec![1623.02, 1662.17],
+                eigenvectors: vec![
+                    vec![0.3666744, -0.9303493],
+                    vec![0.9303493, 0.3666744],
+                ],
+            },
+        ],
+        ped: Ped {
+            freqs: vec![
+                3247.61, 3221.84, 3154.89, 3140.07, 1670.83, 1477.41, 1368.48,
+                1248.31, 1050.25, 963.44, 949.38, 825.52,
+            ],
+            data: load_mat("testfiles/c2h4.ped"),
+        },
+        internal_fcs: load_fcs("testfiles/c2h4.fcs"),
+        perturbed_defects: vec![
+            (vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.0558505)),
+            (vec![0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0], Some(-0.2068399)),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.1105764)),
+            (vec![0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0], Some(-0.2670754)),
+            (vec![0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.0600626)),
+            (vec![0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0], Some(-0.1965865)),
+            (vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0], Some(-0.0739286)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2], Some(0.2409778)),
+        ],
+        rot_energies: load_energies("testfiles/c2h4.rotlev"),
+        state_rots: load_rots("testfiles/c2h4.rots"),
+        vib_states: load_states("testfiles/c2h4.states"),
+        zeta: [
+            load_mat("testfiles/c2h4.zetax"),
+            load_mat("testfiles/c2h4.zetay"),
+            load_mat("testfiles/c2h4.zetaz"),
+        ],
+        msa: HashMap::from([
+            (30, load_mat("testfiles/c2h4.msa30")),
+            (300, load_mat("testfiles/c2h4.msa300")),
+        ]),
+        darling_dennison: DarlingDennison {
+            data: HashMap::from([
+                ((2, 1), Some(-64.923)),
+                ((3, 1), Some(-63.132)),
+                ((3, 2), Some(-66.903)),
+                ((4, 1), Some(-63.405)),
+                ((4, 2), Some(-60.953)),
+                ((4, 3), Some(-55.086)),
+                ((7, 6), Some(-5.531)),
+                ((8, 7), Some(-4.732)),
+                ((10, 9), Some(-9.585)),
+                ((11, 9), Some(-6.766)),
+                ((11, 10), Some(2.754)),
+                ((12, 10), Some(-15.792)),
+                ((12, 11), Some(-11.579)),
+            ]),
+        },
     };
     check!(got, want);
 }
@@ -291,6 +673,8 @@ fn degmode() {
         fund: vec![2886.379, 2799.917, 2221.068, 936.105, 797.174],
         corr: vec![2886.3792, 2799.9172, 2221.0683, 936.1049, 797.1743],
         zpt: 5707.3228,
+        masses: vec![1.007825, 12.0, 12.0, 1.007825],
+        rotor: RotorType::Linear,
         irreps: vec![Ag, B1u, Ag, B2u, B3u, B3g, B2g],
         geom: Molecule::from_str(
             "
@@ -341,6 +725,18 @@ fn degmode() {
             h3: None,
             he: Some(0.0493895173 / 1e6),
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen::default(),
+        wilson: Wilson::default(),
+        tau_prime: TauPrime {
+            aaaa: Some(-0.1643961808),
+            bbbb: Some(-0.1643961808),
+            cccc: Some(0.0),
+            aabb: Some(-0.1643961808),
+            aacc: Some(0.0),
+            bbcc: Some(0.0),
+        },
         fermi: HashMap::new(),
         coriolis: Coriol::default(),
         curvils: vec![
@@ -354,6 +750,44 @@ fn degmode() {
         ],
         ralpha: vec![2.232577, 1.2064508, 2.232577, 0.0264087, 0.0264087],
         requil: vec![2.2367497, 1.2028487, 2.2367497, 0.0, 0.0],
+        rg: vec![2.2386289, 1.2075819, 2.2386289, 0.0264087, 0.0264087],
+        dist: load_mat("testfiles/degmode.dist"),
+        xmat: load_mat("testfiles/degmode.xmat"),
+        alphas: vec![
+            vec![0.0, 55.597119914789744, 0.0],
+            vec![0.0, 9.354540986032621, 0.0],
+            vec![0.0, 117.19878596878605, 0.0],
+            vec![0.0, -26.214467102058897, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, -52.124485969245065, 0.0],
+        ],
+        polyads: vec![],
+        ped: Ped {
+            freqs: vec![
+                2929.50, 2834.26, 2236.67, 939.17, 939.17, 791.07, 791.07,
+            ],
+            data: load_mat("testfiles/degmode.ped"),
+        },
+        internal_fcs: load_fcs("testfiles/degmode.fcs"),
+        perturbed_defects: vec![],
+        rot_energies: vec![],
+        state_rots: load_rots("testfiles/degmode.rots"),
+        vib_states: load_states("testfiles/degmode.states"),
+        zeta: [
+            load_mat("testfiles/degmode.zetax"),
+            load_mat("testfiles/degmode.zetay"),
+            load_mat("testfiles/degmode.zetaz"),
+        ],
+        msa: HashMap::from([
+            (30, load_mat("testfiles/degmode.msa30")),
+            (300, load_mat("testfiles/degmode.msa300")),
+        ]),
+        darling_dennison: DarlingDennison {
+            data: HashMap::from([
+                ((2, 1), Some(-31.474)),
+                ((6, 4), Some(-7.471)),
+            ]),
+        },
     };
     check!(got, want);
 }
@@ -391,6 +825,10 @@ H    2.1461889     -0.2660770      0.0000000
 ",
         )
         .unwrap(),
+        masses: vec![
+            1.007825, 12.0, 12.0, 12.0, 1.007825, 1.007825, 1.007825, 1.007825,
+        ],
+        rotor: RotorType::Asymmetric,
         irreps: vec![
             A1, B2, A1, B2, A1, B2, A1, B2, A1, B2, A2, B1, A1, B1, B2, A2, A1,
             B1,
@@ -449,6 +887,11 @@ H    2.1461889     -0.2660770      0.0000000
             h3: Some(3.4988730580000003e-10),
             he: None,
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen::default(),
+        wilson: Wilson::default(),
+        tau_prime: TauPrime::default(),
         fermi: HashMap::from([
             (4, vec![(7, 6), (8, 7), (9, 6)]),
             (6, vec![(16, 12), (16, 14), (18, 11)]),
@@ -513,6 +956,22 @@ H    2.1461889     -0.2660770      0.0000000
             81.6500202,
             48.3712538,
         ],
+        // testfiles has allyl.lxm but not allyl.out, so there is nothing to
+        // build fixtures like allyl.xmat from for the fields below
+        rg: vec![],
+        dist: vec![],
+        xmat: vec![],
+        alphas: vec![],
+        polyads: vec![],
+        ped: Ped::default(),
+        internal_fcs: ForceConstants::default(),
+        perturbed_defects: vec![],
+        rot_energies: vec![],
+        state_rots: StateRots::default(),
+        vib_states: vec![],
+        zeta: Default::default(),
+        msa: HashMap::new(),
+        darling_dennison: DarlingDennison::default(),
     };
     check!(got, want);
 }
@@ -544,28 +1003,26 @@ H   -1.3465409      1.3195405      0.0000000
 ",
         )
         .unwrap(),
+        masses: vec![12.0, 12.0, 12.0, 1.007825, 1.007825, 1.007825],
+        rotor: RotorType::Oblate,
         irreps: vec![Ap, Ap, Ap, Ap, Ap, Ap, Ap, App, App, Ap, Ap, App],
         lxm: load_mat("testfiles/c3h3.lxm"),
         rots: vec![
-            vec![29590.02824970318, 29589.9952725328, 14756.57323692454],
-            vec![29570.69163616218, 29570.6586589918, 14747.24369563158],
+            vec![29590.01176111799, 14756.57323692454],
+            vec![29570.675147576992, 14747.24369563158],
             vec![29577.73076307602, 29576.4896222999, 14767.209873334381],
             vec![29577.763740246402, 29576.45664512952, 14733.66609520876],
-            vec![29517.34956411024, 29517.316586939858, 14720.29535158196],
+            vec![29517.33307552505, 14720.29535158196],
             vec![29638.930395452142, 29631.07283512796, 14722.711678793441],
             vec![29638.94538507504, 29631.14778324246, 14722.7026850197],
-            vec![29612.365785748763, 29612.32681272922, 14743.23547046812],
+            vec![29612.34629923899, 14743.23547046812],
             vec![29521.16592210058, 29493.67795162656, 14771.236086045321],
             vec![29521.09397191066, 29493.707930872362, 14771.236086045321],
             vec![30741.40516804882, 29641.022946808982, 18647.41466345464],
             vec![30982.64516107684, 29641.0049592615, 10828.9382820241],
             vec![29551.89764697016, 27109.8122674988, 14772.71106493868],
         ],
-        rot_equil: vec![
-            29643.478247040002,
-            29643.478247040002,
-            14821.739123520001,
-        ],
+        rot_equil: vec![29643.478247040002, 14821.739123520001],
         deltas: Delta {
             big_delta_j: Some(0.0335510117),
             big_delta_k: Some(0.0503219399),
@@ -596,6 +1053,29 @@ H   -1.3465409      1.3195405      0.0000000
             h3: Some(2.128595271e-8),
             he: None,
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen {
+            d_j: Some(0.027260013),
+            d_jk: Some(0.0167818353),
+            d_k: Some(0.0188669461),
+            delta_j: Some(0.0146787609),
+            r5: Some(-0.0062925978),
+            r6: Some(-0.0031454994),
+        },
+        wilson: Wilson {
+            d_j: Some(0.0041934899),
+            d_jk: Some(0.0083870538),
+            d_k: Some(0.05032799),
+        },
+        tau_prime: TauPrime {
+            aaaa: Some(-0.2516351776),
+            bbbb: Some(-0.2516341344),
+            cccc: Some(-0.0167739594),
+            aabb: Some(-0.2516595486),
+            aacc: Some(-0.0335478964),
+            bbcc: Some(-0.033548067),
+        },
         fermi: HashMap::from([(
             4,
             vec![(7, 7), (8, 8), (9, 9), (10, 10), (11, 11), (12, 12)],
@@ -618,6 +1098,127 @@ H   -1.3465409      1.3195405      0.0000000
         curvils: vec![],
         ralpha: vec![],
         requil: vec![],
+        rg: vec![],
+        dist: load_mat("testfiles/c3h3.dist"),
+        xmat: load_mat("testfiles/c3h3.xmat"),
+        alphas: vec![
+            vec![19.336601549301683, 19.337827700454902, 9.32823119991854],
+            vec![12.26528292132164, 13.5392419778189, -10.63688823550472],
+            vec![13.54053708123746, 12.26416769337788, 22.90638623878584],
+            vec![72.68060126674662, 72.68080212769348, 36.27815815371678],
+            vec![-41.07767351836946, -48.90258644387326, 33.860853618823704],
+            vec![-48.94743239766549, -41.11834636114632, 33.87106754786776],
+            vec![-22.331501223400462, -22.33578825554986, 13.337541612076501],
+            vec![96.31951238910797, 68.86314603601035, -14.66278016851466],
+            vec![68.90221498913691, 96.32150600895368, -14.66281314568504],
+            vec![-1151.4089151946823, -50.99348594426968, -3890.8425717372893],
+            vec![-1392.6496936789424, -50.97686545039816, 3927.6342953570324],
+            vec![2480.1846808738405, 38.13170896719002, -16.13711450808996],
+        ],
+        polyads: vec![
+            ResonancePolyad {
+                states: vec![vec![(7, 2)], vec![(4, 1)]],
+                energy: vec![vec![2000.402, 8.130], vec![8.130, 1805.303]],
+                eigenvalues: vec![1804.96, 2000.74],
+                eigenvectors: vec![
+                    vec![-0.0415613, -0.9991360],
+                    vec![0.9991360, -0.0415613],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(8, 2)], vec![(4, 1)]],
+                energy: vec![vec![1922.226, 11.955], vec![11.955, 1804.965]],
+                eigenvalues: vec![1803.76, 1923.43],
+                eigenvectors: vec![
+                    vec![-0.1004071, -0.9949464],
+                    vec![0.9949464, -0.1004071],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(9, 2)], vec![(4, 1)]],
+                energy: vec![vec![1922.218, 11.955], vec![11.955, 1803.758]],
+                eigenvalues: vec![1802.56, 1923.41],
+                eigenvectors: vec![
+                    vec![-0.0994213, -0.9950454],
+                    vec![0.9950454, -0.0994213],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(10, 2)], vec![(4, 1)]],
+                energy: vec![vec![1839.424, 10.706], vec![10.706, 1802.564]],
+                eigenvalues: vec![1799.68, 1842.31],
+                eigenvectors: vec![
+                    vec![-0.2601068, -0.9655798],
+                    vec![0.9655798, -0.2601068],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(11, 2)], vec![(4, 1)]],
+                energy: vec![vec![1839.310, 10.712], vec![10.712, 1799.680]],
+                eigenvalues: vec![1796.97, 1842.02],
+                eigenvectors: vec![
+                    vec![-0.2452596, -0.9694574],
+                    vec![0.9694574, -0.2452596],
+                ],
+            },
+            ResonancePolyad {
+                states: vec![vec![(12, 2)], vec![(4, 1)]],
+                energy: vec![vec![1803.294, 12.050], vec![12.050, 1796.970]],
+                eigenvalues: vec![1787.67, 1812.59],
+                eigenvectors: vec![
+                    vec![-0.6108152, -0.7917732],
+                    vec![0.7917732, -0.6108152],
+                ],
+            },
+        ],
+        ped: Ped::default(),
+        internal_fcs: ForceConstants::default(),
+        perturbed_defects: vec![
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.088469)),
+            (vec![0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0], Some(-0.2009662)),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.0856798)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0], Some(0.1664525)),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-0.08573)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0], Some(0.1665502)),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(1.0292945)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0], Some(15.3126595)),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(-1.2751768)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0], None),
+            (vec![0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], Some(1.0201096)),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2], Some(1.774122)),
+        ],
+        rot_energies: load_energies("testfiles/c3h3.rotlev"),
+        state_rots: load_rots("testfiles/c3h3.rots"),
+        vib_states: load_states("testfiles/c3h3.states"),
+        zeta: [
+            load_mat("testfiles/c3h3.zetax"),
+            load_mat("testfiles/c3h3.zetay"),
+            load_mat("testfiles/c3h3.zetaz"),
+        ],
+        msa: HashMap::new(),
+        darling_dennison: DarlingDennison {
+            data: HashMap::from([
+                ((2, 1), Some(-29.588)),
+                ((3, 1), Some(-29.563)),
+                ((3, 2), Some(-15.795)),
+                ((6, 5), Some(-11.341)),
+                ((8, 7), Some(-7.479)),
+                ((9, 7), Some(-7.478)),
+                ((9, 8), Some(1.529)),
+                ((10, 7), Some(-2.762)),
+                ((10, 8), Some(-3.000)),
+                ((10, 9), Some(-2.314)),
+                ((11, 7), Some(-2.743)),
+                ((11, 8), Some(-2.313)),
+                ((11, 9), Some(-3.001)),
+                ((11, 10), Some(12.876)),
+                ((12, 7), Some(-4.714)),
+                ((12, 8), Some(4.905)),
+                ((12, 9), Some(4.904)),
+                ((12, 10), Some(-51.294)),
+                ((12, 11), Some(-51.315)),
+            ]),
+        },
     };
     check!(got, want);
 }
@@ -638,6 +1239,8 @@ H     -0.81157390 -0.46856240 -0.31497400
 ",
         )
         .unwrap(),
+        masses: vec![1.007825, 14.003074, 1.007825, 1.007825],
+        rotor: RotorType::Oblate,
         irreps: vec![App, App, App, App, App, App],
         lxm: load_mat("testfiles/nh3.lxm"),
         rots: vec![
@@ -646,10 +1249,6 @@ H     -0.81157390 -0.46856240 -0.31497400
             vec![293442.5029823842, 184366.47674404946],
             vec![290548.5898967249, 185787.2141879835],
             vec![302593.315506696, 184015.22790855833],
-            vec![286862.6805986345, 183590.59887312257],
-            vec![290829.64232817537, 182541.71500031796],
-            vec![285041.816156857, 185383.18988818608],
-            vec![309131.2673767991, 181839.21732933572],
         ],
         rot_equil: vec![298628.66367804405, 190333.73469733],
         deltas: Delta {
@@ -682,6 +1281,29 @@ H     -0.81157390 -0.46856240 -0.31497400
             h3: Some(-0.0001582976867),
             he: None,
         },
+        derived_reduction: None,
+        isotopologue: None,
+        nielsen: Nielsen {
+            d_j: Some(22.0919735144),
+            d_jk: Some(-39.0714271953),
+            d_k: Some(22.2750749384),
+            delta_j: Some(1.1109e-6),
+            r5: Some(6.368e-7),
+            r6: Some(-1.148e-7),
+        },
+        wilson: Wilson {
+            d_j: Some(22.0919759658),
+            d_jk: Some(-39.0714324236),
+            d_k: Some(22.2750777154),
+        },
+        tau_prime: TauPrime {
+            aaaa: Some(-88.3678860889),
+            bbbb: Some(-88.3679038634),
+            cccc: Some(-21.1824850305),
+            aabb: Some(-88.3678913028),
+            aacc: Some(-10.2250403184),
+            bbcc: Some(-10.2250390161),
+        },
         fermi: HashMap::from([(3, vec![(4, 4)])]),
         coriolis: Coriol::default(),
         zpt: 7448.0683,
@@ -694,8 +1316,689 @@ H     -0.81157390 -0.46856240 -0.31497400
             Angle(2, 1, 4),
             Angle(2, 3, 4),
         ],
-        ralpha: vec![],
-        requil: vec![],
+        ralpha: vec![
+            1.0248664,
+            1.02485,
+            1.02485,
+            106.7928264,
+            106.7928264,
+            106.7932282,
+        ],
+        requil: vec![
+            1.0123625,
+            1.0123625,
+            1.0123625,
+            106.5784645,
+            106.5784645,
+            106.5784642,
+        ],
+        rg: vec![
+            1.0314817,
+            1.0314654,
+            1.0314654,
+            106.4996278,
+            106.4996278,
+            106.5000296,
+        ],
+        dist: load_mat("testfiles/nh3.dist"),
+        xmat: load_mat("testfiles/nh3.xmat"),
+        alphas: vec![
+            vec![0.0, 5506.774189556648, 404.02408994271946],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 4596.34102132373, 1300.319387619759],
+            vec![0.0, -6537.952457696298, 2176.0105552392233],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 2612.8606392191473, 1824.7616987626313],
+        ],
+        polyads: vec![ResonancePolyad {
+            states: vec![vec![(4, 2)], vec![(3, 1)]],
+            energy: vec![vec![3243.316, -54.123], vec![-54.123, 3308.481]],
+            eigenvalues: vec![3212.73, 3339.07],
+            eigenvectors: vec![
+                vec![-0.8705636, -0.4920558],
+                vec![-0.4920558, 0.8705636],
+            ],
+        }],
+        ped: Ped {
+            freqs: vec![3610.26, 3610.26, 3478.53, 1675.58, 1675.58, 1056.96],
+            data: load_mat("testfiles/nh3.ped"),
+        },
+        internal_fcs: load_fcs("testfiles/nh3.fcs"),
+        perturbed_defects: vec![
+            (vec![0, 0, 1, 0], Some(-0.0698547)),
+            (vec![0, 0, 0, 2], Some(-0.1434648)),
+        ],
+        rot_energies: vec![],
+        state_rots: load_rots("testfiles/nh3.rots"),
+        vib_states: load_states("testfiles/nh3.states"),
+        zeta: [
+            load_mat("testfiles/nh3.zetax"),
+            load_mat("testfiles/nh3.zetay"),
+            load_mat("testfiles/nh3.zetaz"),
+        ],
+        msa: HashMap::from([
+            (30, load_mat("testfiles/nh3.msa30")),
+            (300, load_mat("testfiles/nh3.msa300")),
+        ]),
+        darling_dennison: DarlingDennison::default(),
     };
+    // the ground state and one set of constants per fundamental, without the
+    // overtones that are only in state_rots
+    assert_eq!(got.rots.len(), got.harm.len() + 1);
     check!(got, want);
 }
+
+#[test]
+fn mixed_states() {
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    let got = sum.leading_coeffs();
+    assert_eq!(got.len(), sum.corr.len());
+    // ν1 is mixed with 2ν3 in the first polyad, and ν3 appears in the last
+    // three, where it is most strongly mixed with 2ν9
+    assert_eq!(got[0], Some((0, 0.8510134)));
+    assert_eq!(got[2], Some((3, 0.9742076)));
+    assert!(got
+        .iter()
+        .enumerate()
+        .all(|(i, c)| c.is_some() == (i == 0 || i == 2)));
+
+    assert_eq!(sum.mixed_states(0.9), vec![(0, 0, 0.8510134)]);
+    assert_eq!(
+        sum.mixed_states(0.98),
+        vec![(0, 0, 0.8510134), (2, 3, 0.9742076)]
+    );
+    assert!(sum.mixed_states(0.5).is_empty());
+}
+
+#[test]
+fn ped_contributions() {
+    let sum = Summary::new("testfiles/c2h4.out", Recompute::No);
+    // 12 rows of SPECTRO's coordinates for the 13 curvilinear coordinates
+    assert!(!sum.ped.matches_curvils(sum.curvils.len()));
+    for harm in &sum.harm {
+        let got = sum.ped.contributions(*harm);
+        assert_eq!(got.len(), 12);
+        assert!(got.iter().all(|(_, p)| (0.0..=1.0).contains(p)));
+        let total: f64 = got.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+    // the first column has elements of -0.2933481 and 1.4012849 elsewhere, so
+    // normalize by the sum of the absolute values, 1.5970
+    let got = sum.ped.contributions(sum.harm[0]);
+    let want = [(2, 0.3625024), (4, 0.2395785), (0, 0.1836925)];
+    for ((gc, gp), (wc, wp)) in got.iter().zip(want) {
+        assert_eq!(*gc, wc);
+        assert!((gp - wp).abs() < 1e-6, "{gp} {wp}");
+    }
+    assert!(sum.ped.contributions(1234.0).is_empty());
+
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    assert!(sum.ped.matches_curvils(sum.curvils.len()));
+}
+
+#[test]
+fn rms_amplitudes() {
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    let got = sum.rms_amplitudes(30);
+    assert_eq!(got.len(), sum.curvils.len());
+    let want = [0.00169014, 0.00223453, 0.00223453, 0.00557544, 0.00557544];
+    for (g, w) in got.iter().zip(want) {
+        assert_eq!(*g, Some(w.sqrt()));
+    }
+    assert!(got.iter().all(Option::is_some));
+    assert!(sum.rms_amplitudes(100).iter().all(Option::is_none));
+
+    // the 13 coordinates of C2H4 are redundant, so SPECTRO's matrices are not
+    // symmetric and have negative diagonal elements
+    let sum = Summary::new("testfiles/c2h4.out", Recompute::No);
+    assert!(sum.msa[&30][3][3] < 0.0);
+    for temp in [30, 300] {
+        let got = sum.rms_amplitudes(temp);
+        assert_eq!(got.len(), 13);
+        assert!(got.iter().all(Option::is_none));
+    }
+}
+
+#[test]
+fn inertia_defects() {
+    // both molecules are planar, so the equilibrium inertia defect should
+    // vanish
+    let got = Summary::new("testfiles/spectro.out", Recompute::No);
+    assert!(got.equil_inertia_defect().unwrap().abs() < 1e-3);
+    let defects = got.inertia_defects();
+    assert_eq!(defects.len(), got.rots.len());
+    assert!((defects[0].unwrap() - 0.0917).abs() < 1e-4);
+
+    let got = Summary::new("testfiles/c2h4.out", Recompute::No);
+    assert!(got.equil_inertia_defect().unwrap().abs() < 1e-3);
+    assert!((got.inertia_defects()[0].unwrap() - 0.0544).abs() < 1e-4);
+
+    // not an asymmetric top
+    let got = Summary::new("testfiles/nh3.out", Recompute::No);
+    assert!(got.equil_inertia_defect().is_none());
+}
+
+#[test]
+fn geom_rots() {
+    for f in [
+        "testfiles/spectro.out",
+        "testfiles/c2h4.out",
+        "testfiles/degmode.out",
+        "testfiles/c3h3.out",
+        "testfiles/nh3.out",
+    ] {
+        let got = Summary::new(f, Recompute::No);
+        check_rel(got.geom_rot_equil(), got.rot_equil.clone(), 1e-5);
+    }
+
+    // linear molecule has a vanishing moment about the molecular axis
+    let got = Summary::new("testfiles/degmode.out", Recompute::No);
+    let moments = got.principal_moments();
+    assert!(moments[0].abs() < 1e-8);
+    assert!((moments[1] - moments[2]).abs() < 1e-8);
+    let tensor = got.inertia_tensor();
+    assert!((tensor[0][0] - moments[1]).abs() < 1e-8);
+}
+
+#[test]
+fn isotopologues() {
+    let sum = Summary::new("testfiles/nh3.out", Recompute::No);
+    let subs: Vec<Substitution> = vec!["1=D".parse().unwrap()];
+    let got = sum.isotopologue(&subs).unwrap();
+    // NH2D is no longer a symmetric top
+    assert_eq!(got.rotor, RotorType::Asymmetric);
+    assert_eq!(got.label(), "1=D");
+    check_rel(
+        got.rot_equil.clone(),
+        vec![290181.19614, 192819.60764, 142979.71268],
+        1e-7,
+    );
+    let (be, b0) = (&sum.rot_equil, &sum.rots[0]);
+    let want = vec![
+        got.rot_equil[0] - (be[0] - b0[0]),
+        got.rot_equil[1] - (be[0] - b0[0]),
+        got.rot_equil[2] - (be[1] - b0[1]),
+    ];
+    assert_eq!(got.rot0, want);
+
+    // no substitutions should recover the parent
+    let got = sum.isotopologue(&[]).unwrap();
+    assert_eq!(got.rotor, sum.rotor);
+    check_rel(got.rot0, sum.rots[0].clone(), 1e-5);
+
+    assert!(sum.isotopologue(&["5=D".parse().unwrap()]).is_err());
+    assert!("1=Xx".parse::<Substitution>().is_err());
+    assert!("D".parse::<Substitution>().is_err());
+}
+
+#[test]
+fn se_fit() {
+    // pseudo-isotopologues of c3h2 whose "experimental" constants are just
+    // the predicted ground state constants
+    let parent = Summary::new("testfiles/spectro.out", Recompute::No);
+    let mut sums = Vec::new();
+    for subs in [vec![], vec!["1=13C"], vec!["2=13C"], vec!["4=D"]] {
+        let subs: Vec<Substitution> =
+            subs.iter().map(|s| s.parse().unwrap()).collect();
+        let iso = parent.isotopologue(&subs).unwrap();
+        let mut sum = Summary::new("testfiles/spectro.out", Recompute::No);
+        sum.masses = iso.masses;
+        sum.rotor = iso.rotor;
+        sum.rot_equil = iso.rot_equil;
+        sum.rots = vec![iso.rot0];
+        sums.push(sum);
+    }
+    let exp: Vec<_> = sums
+        .iter()
+        .map(|s| semiexp::ExpRots {
+            rots: s.rots[0].clone(),
+            sigma: vec![0.1; 3],
+        })
+        .collect();
+
+    // the parent geometry reproduces these exactly
+    let got = semiexp::fit(&sums, &exp).unwrap();
+    assert!(got.converged);
+    let curvils: Vec<_> =
+        got.params.iter().map(|p| p.curvils.clone()).collect();
+    assert_eq!(curvils, vec![vec![0], vec![1, 2], vec![3, 4], vec![5, 6]]);
+    for p in &got.params {
+        assert!((p.value - p.initial).abs() < 1e-7, "{p:?}");
+    }
+    for r in got.residuals().iter().flatten() {
+        assert!(r.abs() < 1e-4);
+    }
+
+    // scaling every constant by s scales the bonds by 1/√s and leaves the
+    // angles alone
+    let s = 1.01;
+    let exp: Vec<_> = sums
+        .iter()
+        .map(|sum| semiexp::ExpRots {
+            rots: sum
+                .rot_equil
+                .iter()
+                .zip(&sum.rots[0])
+                .map(|(e, z)| (s - 1.0) * e + z)
+                .collect(),
+            sigma: vec![0.1; 3],
+        })
+        .collect();
+    let got = semiexp::fit(&sums, &exp).unwrap();
+    assert!(got.converged);
+    for p in &got.params[..3] {
+        assert!((p.value - p.initial / s.sqrt()).abs() < 1e-7, "{p:?}");
+    }
+    assert!((got.params[3].value - got.params[3].initial).abs() < 1e-6);
+    assert!(got.std_dev < 1e-3);
+
+    // too few constants to determine the parameters
+    assert!(semiexp::fit(&sums[..1], &exp[..1]).is_err());
+    assert!(semiexp::fit(&sums, &exp[..2]).is_err());
+}
+
+#[test]
+fn rot_levels() {
+    use reduction::Representation::{IIIr, Ir};
+    use rot_levels::{energy_levels, transitions, DipoleType};
+
+    // SPECTRO prints the energies to 1e-7 cm⁻¹, or about 0.003 MHz, but it
+    // rounds the rotational constants it used to the same precision, and that
+    // error grows with J(J + 1) to about 0.015 MHz by J = 3
+    let check_energies = |sum: &Summary, want: &RotEnergies| {
+        let (d, p) = (&sum.deltas, &sum.phis);
+        let got =
+            energy_levels(&want.rots, d, p, want.reduction, Ir, 3).unwrap();
+        assert_eq!(got.len(), want.levels.len());
+        // look the levels up by their labels since SPECTRO prints degenerate
+        // pairs of levels in either order
+        for w in &want.levels {
+            let g = got
+                .iter()
+                .find(|g| (g.j, g.ka, g.kc) == (w.j, w.ka, w.kc))
+                .unwrap();
+            assert!((g.energy - w.energy).abs() < 0.015, "{g:?} {w:?}");
+        }
+    };
+    // the ground state A and S reductions come first
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    for want in &sum.rot_energies[..2] {
+        check_energies(&sum, want);
+    }
+    let c2h4 = Summary::new("testfiles/c2h4.out", Recompute::No);
+    for want in &c2h4.rot_energies[..2] {
+        check_energies(&c2h4, want);
+    }
+    // C3H3 is so close to an oblate top that the A reduction is
+    // ill-conditioned, and its K doublet splittings differ from SPECTRO's by
+    // about 0.2 MHz, so only compare the S reduction
+    let c3h3 = Summary::new("testfiles/c3h3.out", Recompute::No);
+    assert_eq!(c3h3.rot_energies[1].reduction, Reduction::S);
+    check_energies(&c3h3, &c3h3.rot_energies[1]);
+
+    let (d, p) = (&sum.deltas, &sum.phis);
+    let got = sum.rot_levels(0, Reduction::A, Ir, 3).unwrap();
+    let want =
+        energy_levels(&sum.rot_energies[0].rots, d, p, Reduction::A, Ir, 3);
+    assert_eq!(Some(got), want);
+
+    // the rigid rotor levels don't depend on the representation
+    let (d, p) = (Delta::default(), Phi::default());
+    let rots = &sum.rots[0];
+    let ir = energy_levels(rots, &d, &p, Reduction::A, Ir, 6).unwrap();
+    let iiir = energy_levels(rots, &d, &p, Reduction::A, IIIr, 6).unwrap();
+    assert_eq!(ir.len(), 49);
+    // sort by the labels since nearly degenerate levels can swap places
+    let label = |l: &Level| (l.j, l.ka, l.kc);
+    let mut a = ir.clone();
+    let mut b = iiir;
+    a.sort_by_key(label);
+    b.sort_by_key(label);
+    for (a, b) in a.iter().zip(&b) {
+        assert_eq!((a.j, a.ka, a.kc), (b.j, b.ka, b.kc));
+        assert!((a.energy - b.energy).abs() < 1e-6);
+    }
+    assert!(energy_levels(&rots[..2], &d, &p, Reduction::A, Ir, 3).is_none());
+
+    for t in transitions(&ir) {
+        assert!(t.freq > 0.0);
+        assert!(t.upper.j.abs_diff(t.lower.j) <= 1);
+        let dka = (t.upper.ka + t.lower.ka) % 2;
+        let dkc = (t.upper.kc + t.lower.kc) % 2;
+        let want = match (dka, dkc) {
+            (0, 1) => DipoleType::A,
+            (1, 1) => DipoleType::B,
+            (1, 0) => DipoleType::C,
+            _ => panic!("forbidden transition {t:?}"),
+        };
+        assert_eq!(t.dipole, want);
+    }
+
+    assert!(sum.rot_levels(1000, Reduction::A, Ir, 3).is_err());
+    let mut sum = sum;
+    sum.deltas = Delta::default();
+    assert!(sum.rot_levels(0, Reduction::S, Ir, 3).is_err());
+}
+
+#[test]
+fn thermo() {
+    use thermo::{expand_degenerate, symmetry_number, to_kj};
+    use RotorType::{Asymmetric, Linear, Oblate, Prolate, Spherical};
+
+    assert_eq!(symmetry_number("C1", Asymmetric), Some(1));
+    assert_eq!(symmetry_number("Cs(XY)", Asymmetric), Some(1));
+    assert_eq!(symmetry_number("C2v(Z, XZ, YZ)", Asymmetric), Some(2));
+    assert_eq!(symmetry_number("C3v", Prolate), Some(3));
+    assert_eq!(symmetry_number("D2h(X, Y, Z)", Asymmetric), Some(4));
+    assert_eq!(symmetry_number("D3d", Oblate), Some(6));
+    assert_eq!(symmetry_number("S4", Asymmetric), Some(2));
+    assert_eq!(symmetry_number("Td", Spherical), Some(12));
+    assert_eq!(symmetry_number("C2v", Linear), Some(1));
+    assert_eq!(symmetry_number("D2h", Linear), Some(2));
+    assert_eq!(symmetry_number("Xyz", Asymmetric), None);
+
+    assert_eq!(
+        expand_degenerate(&[1.0, 2.0, 3.0, 4.0], 2),
+        vec![1.0, 2.0, 3.0, 4.0, 3.0, 4.0]
+    );
+
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    let temps = [100.0, 298.15, 1000.0];
+    let got = sum.thermo(Variant::Harmonic, &temps, None).unwrap();
+    assert_eq!(got.sigma, 2);
+    assert_eq!(got.zpe, to_kj(sum.harm.iter().sum::<f64>() / 2.0));
+    // Sackur-Tetrode entropy of C3H2 at 1 bar
+    let row = &got.rows[1];
+    assert!((row.trans.s - 154.2273).abs() < 1e-4);
+    assert_eq!(row.trans.cp, 2.5 * 8.314462618);
+    assert_eq!(row.rot.cp, 1.5 * 8.314462618);
+
+    // the enthalpy, entropy, and heat capacity should be consistent with the
+    // partition function: H = RT² d(ln q)/dT, S = R ln q + H/T, and Cp =
+    // dH/dT
+    let r = 8.314462618;
+    let dt = 1e-3;
+    for &t in &temps {
+        let rows = sum
+            .thermo(Variant::Harmonic, &[t - dt, t, t + dt], None)
+            .unwrap()
+            .rows;
+        let parts = |i: usize| {
+            let row = rows[i];
+            [row.trans, row.rot, row.vib, row.total()]
+        };
+        for ((lo, mid), hi) in parts(0).iter().zip(parts(1)).zip(parts(2)) {
+            let dlnq = (hi.q.ln() - lo.q.ln()) / (2.0 * dt);
+            assert!((1000.0 * mid.h - r * t * t * dlnq).abs() < 1e-3);
+            assert!((mid.s - r * mid.q.ln() - 1000.0 * mid.h / t).abs() < 1e-8);
+            let cp = 1000.0 * (hi.h - lo.h) / (2.0 * dt);
+            assert!((mid.cp - cp).abs() < 1e-4, "{} {cp}", mid.cp);
+        }
+    }
+
+    let fund = sum.thermo(Variant::Fundamental, &temps, None).unwrap();
+    let anharm = sum.thermo(Variant::Anharmonic, &temps, None).unwrap();
+    assert_eq!(anharm.zpe, to_kj(sum.zpt));
+    assert_eq!(fund.rows, anharm.rows);
+    assert!(fund.rows[2].vib.s > got.rows[2].vib.s);
+    assert_eq!(
+        sum.thermo(Variant::Harmonic, &temps, Some(1))
+            .unwrap()
+            .sigma,
+        1
+    );
+
+    // the degenerate modes of NH3 are counted twice
+    let sum = Summary::new("testfiles/nh3.out", Recompute::No);
+    let got = sum.thermo(Variant::Harmonic, &[1e6], Some(3)).unwrap();
+    assert!((got.rows[0].vib.cp - 6.0 * r).abs() < 1e-3);
+
+    // the point groups of symmetric tops are only detected as their abelian
+    // subgroups, Cs for both NH3 and C3H3+, so their symmetry numbers come
+    // from counting rotations instead
+    let got = sum.thermo(Variant::Harmonic, &temps, None).unwrap();
+    assert_eq!(got.sigma, 3);
+    let mut sum = sum;
+    sum.geom.atoms.clear();
+    assert!(sum.thermo(Variant::Harmonic, &temps, None).is_err());
+    assert_eq!(
+        sum.thermo(Variant::Harmonic, &temps, Some(3))
+            .unwrap()
+            .sigma,
+        3
+    );
+
+    let sum = Summary::new("testfiles/c3h3.out", Recompute::No);
+    let got = sum.thermo(Variant::Harmonic, &temps, None).unwrap();
+    assert_eq!(got.sigma, 6);
+
+    let sum = Summary::new("testfiles/c2h4.out", Recompute::No);
+    let got = sum.thermo(Variant::Harmonic, &temps, None).unwrap();
+    assert_eq!(got.sigma, 4);
+}
+
+#[test]
+fn rotor_types() {
+    let eps = ROTOR_EPS;
+    assert_eq!(
+        RotorType::from_moments([0.0, 14.07, 14.07], eps),
+        RotorType::Linear
+    );
+    assert_eq!(
+        RotorType::from_moments([3.2, 3.2, 3.2], eps),
+        RotorType::Spherical
+    );
+    assert_eq!(
+        RotorType::from_moments([20.2, 3.4, 20.2], eps),
+        RotorType::Prolate
+    );
+    assert_eq!(
+        RotorType::from_moments([1.69, 1.69, 2.66], eps),
+        RotorType::Oblate
+    );
+    assert_eq!(
+        RotorType::from_moments([14.4, 15.7, 30.1], eps),
+        RotorType::Asymmetric
+    );
+
+    assert_eq!(
+        RotorType::Prolate.unique_rots(&[4.0, 8.0, 5.0]),
+        vec![8.0, 4.5]
+    );
+    assert_eq!(
+        RotorType::Oblate.unique_rots(&[4.0, 8.0, 6.0]),
+        vec![7.0, 4.0]
+    );
+    assert_eq!(RotorType::Oblate.abc(&[7.0, 4.0], 0), None);
+    assert_eq!(RotorType::Oblate.abc(&[7.0, 4.0], 2), Some(&4.0));
+
+    // split by more than SPLIT_EPS, so all three are kept
+    let split = RotorType::Oblate.unique_state_rots(&[4.0, 8.0, 6.0]);
+    assert_eq!(split, vec![8.0, 6.0, 4.0]);
+    assert_eq!(RotorType::Oblate.abc(&split, 0), Some(&8.0));
+    assert_eq!(
+        RotorType::Oblate.unique_state_rots(&[4.0, 8.0, 8.0625]),
+        vec![8.03125, 4.0]
+    );
+}
+
+/// assert that every value in `got` is within a relative tolerance of `eps` of
+/// the corresponding value in `want`
+fn check_rel(got: Vec<f64>, want: Vec<f64>, eps: f64) {
+    assert_eq!(got.len(), want.len());
+    for (g, w) in got.iter().zip(&want) {
+        assert!(((g - w) / w).abs() < eps, "got {got:?}, wanted {want:?}");
+    }
+}
+
+#[test]
+fn reductions() {
+    let want = Summary::new("testfiles/spectro.out", Recompute::No);
+    let rots = &want.rot_equil;
+    let rep = Representation::Ir;
+
+    let s = want.deltas.a_to_s(rots, rep).unwrap();
+    check_rel(
+        s.to_vec()[5..10].to_vec(),
+        want.deltas.to_vec()[5..10].to_vec(),
+        1e-6,
+    );
+    let a = want.deltas.s_to_a(rots, rep).unwrap();
+    check_rel(
+        a.to_vec()[..5].to_vec(),
+        want.deltas.to_vec()[..5].to_vec(),
+        1e-6,
+    );
+
+    // spectro's sextic conversions differ from these in the sixth digit or so
+    let s = want.phis.a_to_s(&want.deltas, rots, rep).unwrap();
+    check_rel(
+        s.to_vec()[7..14].to_vec(),
+        want.phis.to_vec()[7..14].to_vec(),
+        1e-4,
+    );
+    let a = want.phis.s_to_a(&want.deltas, rots, rep).unwrap();
+    check_rel(
+        a.to_vec()[..7].to_vec(),
+        want.phis.to_vec()[..7].to_vec(),
+        1e-4,
+    );
+
+    // drop the S reduction and fill it back in
+    let mut got = Summary::new("testfiles/spectro.out", Recompute::No);
+    got.deltas = Delta {
+        d_j: None,
+        d_jk: None,
+        d_k: None,
+        d1: None,
+        d2: None,
+        ..got.deltas
+    };
+    got.phis = Phi {
+        h_j: None,
+        h_jk: None,
+        h_kj: None,
+        h_k: None,
+        h1: None,
+        h2: None,
+        h3: None,
+        ..got.phis
+    };
+    assert_eq!(got.fill_reduction(rep), Some(Reduction::S));
+    assert_eq!(got.derived_reduction, Some(Reduction::S));
+    check_rel(
+        got.deltas.to_vec()[..10].to_vec(),
+        want.deltas.to_vec()[..10].to_vec(),
+        1e-6,
+    );
+    check_rel(
+        got.phis.to_vec()[..14].to_vec(),
+        want.phis.to_vec()[..14].to_vec(),
+        1e-4,
+    );
+
+    // nothing to fill in
+    assert_eq!(got.fill_reduction(rep), None);
+}
+
+/// the JSON output from Rust spectro does not include the Coriolis zeta
+/// constants, so they should be left empty
+#[test]
+fn json_zeta() {
+    let got = Summary::new("testfiles/spec.json", Recompute::No);
+    assert!(got.zeta.is_empty());
+}
+
+#[test]
+fn try_new_errors() {
+    assert!(matches!(
+        Summary::try_new("testfiles", Recompute::No),
+        Err(ParseError::IsDir(_))
+    ));
+    assert!(matches!(
+        Summary::try_new("testfiles/missing.out", Recompute::No),
+        Err(ParseError::Io { .. })
+    ));
+    match Summary::try_new("testfiles/badcurvil.out", Recompute::No) {
+        Err(ParseError::Line {
+            line, state, text, ..
+        }) => {
+            assert_eq!(line, 7);
+            assert_eq!(state, State::Curvil);
+            assert!(text.contains("WAG"));
+        }
+        got => panic!("expected ParseError::Line, got {got:?}"),
+    }
+    match Summary::try_new("testfiles/badutf8.out", Recompute::No) {
+        Err(ParseError::Io { line, .. }) => assert_eq!(line, Some(2)),
+        got => panic!("expected ParseError::Io, got {got:?}"),
+    }
+}
+
+#[test]
+fn vpt2() {
+    use vpt2::{direct_product, resonances, ResonanceType};
+
+    assert_eq!(direct_product(&A2, &B1), Some(B2));
+    assert_eq!(direct_product(&B1, &B1), Some(A1));
+    assert_eq!(direct_product(&B1u, &B2u), Some(B3g));
+    assert_eq!(direct_product(&Au, &B1g), Some(B1u));
+    assert_eq!(direct_product(&Au, &Au), Some(Ag));
+    assert_eq!(direct_product(&Ap, &App), Some(App));
+    assert_eq!(direct_product(&A1, &Ap), None);
+    assert_eq!(direct_product(&E, &A1), None);
+
+    let sum = Summary::new("testfiles/spectro.out", Recompute::No);
+    assert!(sum.vpt2_levels(None, None).is_err());
+
+    let got = sum.vpt2_levels(Some(2), None).unwrap();
+    // 9 fundamentals, 9 overtones, and 36 combination bands
+    assert_eq!(got.len(), 54);
+    assert!(got
+        .windows(2)
+        .all(|w| w[0].state.zpt_rel <= w[1].state.zpt_rel));
+    // without resonances, the fundamentals should match SPECTRO's
+    for (i, fund) in sum.fund.iter().enumerate() {
+        let level = got
+            .iter()
+            .find(|l| l.state.modes() == vec![(i + 1, 1)])
+            .unwrap();
+        assert!((level.state.zpt_rel - fund).abs() < 0.005);
+        assert_eq!(level.symmetry, sum.irreps.get(i).cloned());
+    }
+    let mut quanta = vec![0; 9];
+    quanta[0] = 1;
+    quanta[1] = 1;
+    let level = got.iter().find(|l| l.state.quanta == quanta).unwrap();
+    assert!((level.state.zpt_rel - 6141.6796).abs() < 0.005);
+    assert_eq!(level.symmetry, Some(B2));
+    assert!(got
+        .iter()
+        .all(|l| l.state.energy == sum.zpt + l.state.zpt_rel));
+
+    let cut = sum.vpt2_levels(Some(2), Some(3200.0)).unwrap();
+    assert_eq!(cut.len(), 37);
+    assert!(cut.iter().all(|l| l.state.zpt_rel <= 3200.0));
+    // the energy cutoff alone should find the same levels
+    assert_eq!(sum.vpt2_levels(None, Some(3200.0)).unwrap().len(), 37);
+
+    // ν1 and 2ν3 are both A1 and about 21 cm⁻¹ apart
+    let res = resonances(&cut, 25.0);
+    let r = res
+        .iter()
+        .find(|r| {
+            let (lo, hi) = r.levels;
+            cut[lo].state.modes() == vec![(1, 1)]
+                && cut[hi].state.modes() == vec![(3, 2)]
+        })
+        .unwrap();
+    assert_eq!(r.kind, ResonanceType::Fermi);
+    assert!((r.gap - 21.3325).abs() < 0.005);
+    assert!(res.iter().all(|r| {
+        let (lo, hi) = r.levels;
+        r.gap <= 25.0 && cut[lo].symmetry == cut[hi].symmetry
+    }));
+
+    let deg = Summary::new("testfiles/degmode.out", Recompute::No);
+    assert!(deg.vpt2_levels(Some(2), None).is_err());
+}
@@ -0,0 +1,341 @@
+//! rigid rotor/harmonic oscillator thermochemistry
+
+use std::str::FromStr;
+
+use symm::Molecule;
+
+use crate::rotor::RotorType;
+
+/// the second radiation constant hc/k in cm K
+const C2: f64 = 1.438776877;
+
+/// the molar gas constant in J/(mol K)
+const R: f64 = 8.314462618;
+
+/// Boltzmann's constant in J/K
+const KB: f64 = 1.380649e-23;
+
+/// Planck's constant in J s
+const H: f64 = 6.62607015e-34;
+
+/// the atomic mass constant in kg
+const AMU: f64 = 1.66053906660e-27;
+
+/// the standard pressure in Pa
+const PRESSURE: f64 = 1e5;
+
+/// the highest order of rotation axis considered by [rotation_count]
+const MAX_ORDER: usize = 8;
+
+/// convert an energy in cm⁻¹ to kJ/mol
+pub fn to_kj(v: f64) -> f64 {
+    v * R * C2 / 1000.0
+}
+
+/// the vibrational frequencies and zero-point energy to use in the
+/// vibrational partition function
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Variant {
+    /// harmonic frequencies and half their sum as the ZPE
+    #[default]
+    Harmonic,
+
+    /// resonance-corrected anharmonic fundamentals and half their sum as the
+    /// ZPE
+    Fundamental,
+
+    /// resonance-corrected anharmonic fundamentals and SPECTRO's anharmonic
+    /// ZPT
+    Anharmonic,
+}
+
+impl FromStr for Variant {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "harm" | "harmonic" => Ok(Self::Harmonic),
+            "fund" | "fundamental" => Ok(Self::Fundamental),
+            "anharm" | "anharmonic" => Ok(Self::Anharmonic),
+            _ => Err(format!("unrecognized thermochemistry variant '{s}'")),
+        }
+    }
+}
+
+/// the contribution of one kind of motion to the thermodynamic functions at a
+/// single temperature
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Contribution {
+    /// partition function
+    pub q: f64,
+
+    /// enthalpy H(T) - H(0) in kJ/mol
+    pub h: f64,
+
+    /// entropy in J/(mol K)
+    pub s: f64,
+
+    /// constant-pressure heat capacity in J/(mol K)
+    pub cp: f64,
+}
+
+/// the translational, rotational, and vibrational contributions to the
+/// thermodynamic functions at a single temperature
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermoRow {
+    /// temperature in K
+    pub temp: f64,
+
+    pub trans: Contribution,
+    pub rot: Contribution,
+    pub vib: Contribution,
+}
+
+impl ThermoRow {
+    /// return the sum of the three contributions, or their product for the
+    /// partition function. the enthalpy does not include the ZPE
+    pub fn total(&self) -> Contribution {
+        let [t, r, v] = [self.trans, self.rot, self.vib];
+        Contribution {
+            q: t.q * r.q * v.q,
+            h: t.h + r.h + v.h,
+            s: t.s + r.s + v.s,
+            cp: t.cp + r.cp + v.cp,
+        }
+    }
+}
+
+/// thermodynamic functions of an ideal gas of a single molecule at standard
+/// pressure over a range of temperatures
+#[derive(Clone, Debug, PartialEq)]
+pub struct Thermo {
+    pub variant: Variant,
+
+    /// rotational symmetry number
+    pub sigma: usize,
+
+    /// zero-point energy in kJ/mol
+    pub zpe: f64,
+
+    pub rows: Vec<ThermoRow>,
+}
+
+/// the molecular properties entering the partition functions
+pub struct Species<'a> {
+    /// total mass in amu
+    pub mass: f64,
+
+    /// the A, B, and C rotational constants in MHz, with A missing for linear
+    /// molecules
+    pub rots: [Option<f64>; 3],
+
+    /// rotational symmetry number
+    pub sigma: usize,
+
+    /// vibrational frequencies in cm⁻¹, with each component of a degenerate
+    /// mode listed separately
+    pub freqs: &'a [f64],
+}
+
+impl Species<'_> {
+    /// return the translational contribution at temperature `t`
+    fn trans(&self, t: f64) -> Contribution {
+        let kt = KB * t;
+        let m = self.mass * AMU;
+        let q = (2.0 * std::f64::consts::PI * m * kt / (H * H)).powf(1.5) * kt
+            / PRESSURE;
+        Contribution {
+            q,
+            h: 2.5 * R * t / 1000.0,
+            s: R * (q.ln() + 2.5),
+            cp: 2.5 * R,
+        }
+    }
+
+    /// return the rotational contribution at temperature `t`, in the high
+    /// temperature limit
+    fn rot(&self, t: f64) -> Contribution {
+        // rotational temperatures in K
+        let theta = |b: f64| H * b * 1e6 / KB;
+        let sigma = self.sigma as f64;
+        match self.rots {
+            [Some(a), Some(b), Some(c)] => {
+                let q = std::f64::consts::PI.sqrt() / sigma
+                    * (t.powi(3) / (theta(a) * theta(b) * theta(c))).sqrt();
+                Contribution {
+                    q,
+                    h: 1.5 * R * t / 1000.0,
+                    s: R * (q.ln() + 1.5),
+                    cp: 1.5 * R,
+                }
+            }
+            [None, Some(b), _] => {
+                let q = t / (sigma * theta(b));
+                Contribution {
+                    q,
+                    h: R * t / 1000.0,
+                    s: R * (q.ln() + 1.0),
+                    cp: R,
+                }
+            }
+            _ => Contribution {
+                q: 1.0,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// return the vibrational contribution at temperature `t`, with energies
+    /// measured from the vibrational ground state
+    fn vib(&self, t: f64) -> Contribution {
+        let mut ret = Contribution {
+            q: 1.0,
+            ..Default::default()
+        };
+        for &nu in self.freqs {
+            let x = C2 * nu / t;
+            let e = (-x).exp();
+            ret.q /= 1.0 - e;
+            ret.h += to_kj(nu) * e / (1.0 - e);
+            ret.s += R * (x * e / (1.0 - e) - (1.0 - e).ln());
+            ret.cp += R * x * x * e / ((1.0 - e) * (1.0 - e));
+        }
+        ret
+    }
+
+    /// compute the thermodynamic functions at each temperature in `temps`
+    pub fn rows(&self, temps: &[f64]) -> Vec<ThermoRow> {
+        temps
+            .iter()
+            .map(|&temp| ThermoRow {
+                temp,
+                trans: self.trans(temp),
+                rot: self.rot(temp),
+                vib: self.vib(temp),
+            })
+            .collect()
+    }
+}
+
+/// return the rotational symmetry number for the point group named `pg`,
+/// which should start with its Schoenflies symbol, like `C2v` or `D2h(X, Y,
+/// Z)`. linear molecules are treated as C∞v or D∞h depending on whether `pg`
+/// is a C or D group. returns `None` for unrecognized point groups
+pub fn symmetry_number(pg: &str, rotor: RotorType) -> Option<usize> {
+    let name = pg.split('(').next()?.trim();
+    let mut chars = name.chars();
+    let family = chars.next()?;
+    let rest = chars.as_str();
+    let digits: String =
+        rest.chars().take_while(char::is_ascii_digit).collect();
+    let suffix = &rest[digits.len()..];
+    if rotor == RotorType::Linear {
+        return match family {
+            'C' => Some(1),
+            'D' => Some(2),
+            _ => None,
+        };
+    }
+    let n: Option<usize> = digits.parse().ok();
+    match (family, n, suffix) {
+        ('C', None, "s" | "i") => Some(1),
+        ('C', Some(n), "" | "v" | "h") => Some(n),
+        ('D', Some(n), "" | "h" | "d") => Some(2 * n),
+        ('S', Some(n), "") if n % 2 == 0 => Some(n / 2),
+        ('T', None, "" | "d" | "h") => Some(12),
+        ('O', None, "" | "h") => Some(24),
+        ('I', None, "" | "h") => Some(60),
+        _ => None,
+    }
+}
+
+/// return the number of proper rotations, including the identity, that map
+/// the atoms in `geom` with the corresponding `masses` onto atoms of the same
+/// mass within `eps`. this is the rotational symmetry number of a nonlinear
+/// molecule, including the symmetric and spherical tops whose point groups
+/// are not abelian. the candidate axes run from the center of mass through
+/// each atom, the midpoint of each pair of atoms, and the centroid of each
+/// triple of atoms, or along the normal to each pair, which covers the axes
+/// of every point group with rotations up to order [MAX_ORDER]
+pub fn rotation_count(geom: &Molecule, masses: &[f64], eps: f64) -> usize {
+    let total: f64 = masses.iter().sum();
+    let mut com = [0.0; 3];
+    for (m, a) in masses.iter().zip(&geom.atoms) {
+        for (c, x) in com.iter_mut().zip([a.x, a.y, a.z]) {
+            *c += m * x / total;
+        }
+    }
+    let atoms: Vec<_> = masses
+        .iter()
+        .zip(&geom.atoms)
+        .map(|(&m, a)| (m, [a.x - com[0], a.y - com[1], a.z - com[2]]))
+        .collect();
+
+    let dot = |a: &[f64; 3], b: &[f64; 3]| -> f64 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    };
+    let add =
+        |a: &[f64; 3], b: &[f64; 3]| [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+    let cross = |a: &[f64; 3], b: &[f64; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+
+    let mut cands: Vec<_> = atoms.iter().map(|(_, r)| *r).collect();
+    for (i, (_, a)) in atoms.iter().enumerate() {
+        for (j, (_, b)) in atoms.iter().enumerate().skip(i + 1) {
+            cands.push(add(a, b));
+            cands.push(cross(a, b));
+            for (_, c) in &atoms[j + 1..] {
+                cands.push(add(&add(a, b), c));
+            }
+        }
+    }
+    let mut axes: Vec<[f64; 3]> = Vec::new();
+    for v in cands {
+        let norm = dot(&v, &v).sqrt();
+        if norm < eps {
+            continue;
+        }
+        let u = v.map(|x| x / norm);
+        if axes.iter().all(|w| (dot(&u, w).abs() - 1.0).abs() >= eps) {
+            axes.push(u);
+        }
+    }
+
+    // Rodrigues' rotation of every atom by 2π/n about the unit vector u
+    let maps = |u: &[f64; 3], n: usize| {
+        let (sin, cos) = (2.0 * std::f64::consts::PI / n as f64).sin_cos();
+        atoms.iter().all(|(m, r)| {
+            let (d, x) = (dot(u, r), cross(u, r));
+            let q: Vec<_> = (0..3)
+                .map(|i| r[i] * cos + x[i] * sin + u[i] * d * (1.0 - cos))
+                .collect();
+            atoms.iter().any(|(m2, r2)| {
+                let dist: f64 =
+                    q.iter().zip(r2).map(|(a, b)| (a - b).powi(2)).sum();
+                (m - m2).abs() < eps && dist.sqrt() < eps
+            })
+        })
+    };
+    // the rotations about each axis form a cyclic group, so only the highest
+    // order needs to be found
+    1 + axes
+        .iter()
+        .filter_map(|u| (2..=MAX_ORDER).rev().find(|&n| maps(u, n)))
+        .map(|n| n - 1)
+        .sum::<usize>()
+}
+
+/// return the frequencies in `freqs` with the last `ndeg` repeated, since
+/// SPECTRO lists the non-degenerate modes first and each degenerate mode only
+/// once
+pub fn expand_degenerate(freqs: &[f64], ndeg: usize) -> Vec<f64> {
+    let nondeg = freqs.len().saturating_sub(ndeg);
+    let mut ret = freqs.to_vec();
+    ret.extend_from_slice(&freqs[nondeg..]);
+    ret
+}
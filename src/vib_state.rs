@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// a vibrational state from the STATE NO. section of a SPECTRO output file
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct VibState {
+    /// number of quanta in each mode, in the same order as
+    /// [crate::Summary::corr]
+    pub quanta: Vec<usize>,
+
+    /// energy above the zero-point energy, in cm⁻¹
+    pub zpt_rel: f64,
+
+    /// total energy including the zero-point energy, in cm⁻¹
+    pub energy: f64,
+}
+
+impl VibState {
+    /// return the excited modes as (mode, quanta) pairs, where the modes are
+    /// numbered from 1
+    pub fn modes(&self) -> Vec<(usize, usize)> {
+        self.quanta
+            .iter()
+            .enumerate()
+            .filter(|(_, q)| **q > 0)
+            .map(|(i, q)| (i + 1, *q))
+            .collect()
+    }
+
+    /// return the total number of quanta in `self`
+    pub fn total_quanta(&self) -> usize {
+        self.quanta.iter().sum()
+    }
+
+    /// returns `true` if `self` is the ground state
+    pub fn is_ground(&self) -> bool {
+        self.total_quanta() == 0
+    }
+
+    /// returns `true` if `self` is a fundamental
+    pub fn is_fundamental(&self) -> bool {
+        self.total_quanta() == 1
+    }
+
+    /// returns `true` if `self` is an overtone of a single mode
+    pub fn is_overtone(&self) -> bool {
+        let modes = self.modes();
+        modes.len() == 1 && modes[0].1 > 1
+    }
+
+    /// returns `true` if `self` is a combination of more than one mode
+    pub fn is_combination(&self) -> bool {
+        self.modes().len() > 1
+    }
+}
@@ -0,0 +1,239 @@
+//! predicting overtone and combination bands from the VPT2 energy expression
+
+use symm::Irrep;
+
+use crate::vib_state::VibState;
+
+/// a vibrational level predicted from the harmonic frequencies and the
+/// anharmonic constants
+#[derive(Clone, Debug, PartialEq)]
+pub struct Level {
+    pub state: VibState,
+
+    /// direct product of the irreps of the modes, or `None` if it could not
+    /// be determined
+    pub symmetry: Option<Irrep>,
+}
+
+/// the kind of anharmonic coupling that can connect two [Level]s
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResonanceType {
+    /// the cubic coupling between states like νᵢ and 2νⱼ or νⱼ + νₖ
+    Fermi,
+
+    /// the quartic coupling between states like 2νᵢ and 2νⱼ or νᵢ + νⱼ and
+    /// νₖ + νₗ
+    DarlingDennison,
+}
+
+/// a pair of near-degenerate [Level]s that could be in resonance
+#[derive(Clone, Debug, PartialEq)]
+pub struct Resonance {
+    /// indices of the lower and upper level in the slice passed to
+    /// [resonances]
+    pub levels: (usize, usize),
+
+    pub kind: ResonanceType,
+
+    /// energy difference between the levels in cm⁻¹
+    pub gap: f64,
+}
+
+/// return the VPT2 energy in cm⁻¹ above the ground state of the state with
+/// `quanta` in each mode, given the harmonic frequencies `harm` and the full,
+/// symmetric matrix of anharmonic constants `xmat`
+pub fn energy(harm: &[f64], xmat: &[Vec<f64>], quanta: &[usize]) -> f64 {
+    let v: Vec<f64> = quanta.iter().map(|&q| q as f64).collect();
+    let mut ret = 0.0;
+    for (i, vi) in v.iter().enumerate() {
+        ret += harm[i] * vi;
+        ret += xmat[i][i] * (vi * vi + vi);
+        for (j, vj) in v.iter().enumerate().skip(i + 1) {
+            ret += xmat[i][j] * (vi * vj + 0.5 * (vi + vj));
+        }
+    }
+    ret
+}
+
+/// enumerate the states with at least one and at most `max_quanta` quanta
+/// and an energy no higher than `max_energy`, given the harmonic frequencies
+/// `harm` and anharmonic constants `xmat`. states are built up by adding one
+/// quantum at a time, so states above `max_energy` are never extended
+pub fn states(
+    harm: &[f64],
+    xmat: &[Vec<f64>],
+    max_quanta: usize,
+    max_energy: f64,
+) -> Vec<(Vec<usize>, f64)> {
+    let mut ret = Vec::new();
+    let mut stack = vec![(vec![0; harm.len()], 0, 0)];
+    // each state is reached only once by adding quanta to modes no lower than
+    // the last one incremented
+    while let Some((quanta, first, total)) = stack.pop() {
+        if total == max_quanta {
+            continue;
+        }
+        for i in first..harm.len() {
+            let mut next = quanta.clone();
+            next[i] += 1;
+            let e = energy(harm, xmat, &next);
+            if e > max_energy {
+                continue;
+            }
+            ret.push((next.clone(), e));
+            stack.push((next, i, total + 1));
+        }
+    }
+    ret
+}
+
+/// the families of abelian point groups whose irreps share a labeling scheme
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Family {
+    /// C1 and C2
+    Plain,
+    /// Cs
+    Prime,
+    C2v,
+    C2h,
+    D2h,
+    /// Ag and Au, which are shared by Ci, C2h, and D2h
+    Parity,
+}
+
+/// return the family of `ir` and its characters encoded as bits. the product
+/// of two irreps in the same family is the XOR of their bits
+fn encode(ir: &Irrep) -> Option<(Family, u8)> {
+    use Family::*;
+    Some(match ir {
+        Irrep::A => (Plain, 0),
+        Irrep::B => (Plain, 1),
+        Irrep::Ap => (Prime, 0),
+        Irrep::App => (Prime, 1),
+        Irrep::A1 => (C2v, 0),
+        Irrep::B1 => (C2v, 1),
+        Irrep::B2 => (C2v, 2),
+        Irrep::A2 => (C2v, 3),
+        Irrep::Ag => (Parity, 0),
+        Irrep::Au => (Parity, 4),
+        Irrep::Bg => (C2h, 1),
+        Irrep::Bu => (C2h, 5),
+        Irrep::B1g => (D2h, 1),
+        Irrep::B2g => (D2h, 2),
+        Irrep::B3g => (D2h, 3),
+        Irrep::B1u => (D2h, 5),
+        Irrep::B2u => (D2h, 6),
+        Irrep::B3u => (D2h, 7),
+        _ => return None,
+    })
+}
+
+/// the inverse of [encode]
+fn decode(family: Family, bits: u8) -> Option<Irrep> {
+    use Family::*;
+    Some(match (family, bits) {
+        (Plain, 0) => Irrep::A,
+        (Plain, 1) => Irrep::B,
+        (Prime, 0) => Irrep::Ap,
+        (Prime, 1) => Irrep::App,
+        (C2v, 0) => Irrep::A1,
+        (C2v, 1) => Irrep::B1,
+        (C2v, 2) => Irrep::B2,
+        (C2v, 3) => Irrep::A2,
+        (_, 0) => Irrep::Ag,
+        (_, 4) => Irrep::Au,
+        (C2h, 1) => Irrep::Bg,
+        (C2h, 5) => Irrep::Bu,
+        (D2h, 1) => Irrep::B1g,
+        (D2h, 2) => Irrep::B2g,
+        (D2h, 3) => Irrep::B3g,
+        (D2h, 5) => Irrep::B1u,
+        (D2h, 6) => Irrep::B2u,
+        (D2h, 7) => Irrep::B3u,
+        _ => return None,
+    })
+}
+
+/// return the direct product of the irreps `a` and `b`, or `None` if they are
+/// not both non-degenerate irreps of the same abelian point group
+pub fn direct_product(a: &Irrep, b: &Irrep) -> Option<Irrep> {
+    let (fa, x) = encode(a)?;
+    let (fb, y) = encode(b)?;
+    let family = match (fa, fb) {
+        (f, Family::Parity) | (Family::Parity, f) => f,
+        (f, g) if f == g => f,
+        _ => return None,
+    };
+    decode(family, x ^ y)
+}
+
+/// return the symmetry of the state with `quanta` in each mode, where the
+/// modes have symmetries `irreps`. only the modes with an odd number of
+/// quanta contribute, since the square of any non-degenerate irrep is the
+/// totally symmetric irrep
+pub fn symmetry(irreps: &[Irrep], quanta: &[usize]) -> Option<Irrep> {
+    let first = irreps.first()?;
+    let mut ret = direct_product(first, first)?;
+    for (ir, q) in irreps.iter().zip(quanta) {
+        if q % 2 == 1 {
+            ret = direct_product(&ret, ir)?;
+        }
+    }
+    Some(ret)
+}
+
+/// return the type of anharmonic coupling connecting the states with quanta
+/// `a` and `b`, if any. the cubic force constants connect states differing by
+/// one quantum in one mode and two in others, and the quartic force constants
+/// connect states differing by two quanta each way
+fn coupling(a: &[usize], b: &[usize]) -> Option<ResonanceType> {
+    let (mut up, mut down) = (0, 0);
+    for (x, y) in a.iter().zip(b) {
+        if x > y {
+            down += x - y;
+        } else {
+            up += y - x;
+        }
+    }
+    match (up.min(down), up.max(down)) {
+        (1, 2) => Some(ResonanceType::Fermi),
+        (2, 2) => Some(ResonanceType::DarlingDennison),
+        _ => None,
+    }
+}
+
+/// return the pairs of `levels` within `window` cm⁻¹ of each other that have
+/// the same symmetry and could be coupled by a Fermi or Darling-Dennison
+/// resonance, sorted by the energy gap. levels with an unknown symmetry are
+/// treated as having the same symmetry as any other level
+pub fn resonances(levels: &[Level], window: f64) -> Vec<Resonance> {
+    let mut ret = Vec::new();
+    for (i, a) in levels.iter().enumerate() {
+        for (j, b) in levels.iter().enumerate().skip(i + 1) {
+            let gap = (a.state.zpt_rel - b.state.zpt_rel).abs();
+            if gap > window {
+                continue;
+            }
+            if let (Some(x), Some(y)) = (&a.symmetry, &b.symmetry) {
+                if x != y {
+                    continue;
+                }
+            }
+            let Some(kind) = coupling(&a.state.quanta, &b.state.quanta) else {
+                continue;
+            };
+            let pair = if a.state.zpt_rel <= b.state.zpt_rel {
+                (i, j)
+            } else {
+                (j, i)
+            };
+            ret.push(Resonance {
+                levels: pair,
+                kind,
+                gap,
+            });
+        }
+    }
+    ret.sort_by(|a, b| a.gap.total_cmp(&b.gap));
+    ret
+}
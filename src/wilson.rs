@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// quartic centrifugal distortion constants in Wilson's representation, in
+/// MHz
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Wilson {
+    pub d_j: Option<f64>,
+    pub d_jk: Option<f64>,
+    pub d_k: Option<f64>,
+}
+
+impl Display for Wilson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_dist_consts!(f, self,
+        d_j => "DJ",
+        d_jk => "DJK",
+        d_k => "DK",
+           );
+
+        Ok(())
+    }
+}
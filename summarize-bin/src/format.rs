@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
-use summarize::{curvil::Curvil, Summary};
+use summarize::{
+    curvil::Curvil, polyad::ResonancePolyad, reduction::Reduction,
+    rotor::RotorType, Summary,
+};
 use symm::Irrep;
 
 #[allow(unused)]
@@ -9,9 +12,102 @@ pub enum TableType {
     Rot,
     DistA,
     DistS,
+    Nielsen,
+    Wilson,
+    TauPrime,
     Curvil,
     Fermi,
     Coriol,
+    Darling,
+    Xmat,
+    Alpha,
+    Fc,
+    Zeta,
+    Overtones,
+    Dist,
+}
+
+/// optional tables that are only printed when requested
+#[derive(Clone, Copy, Debug, PartialEq, clap::ValueEnum)]
+pub enum Extra {
+    /// anharmonic constants
+    Xmat,
+
+    /// vibration-rotation interaction constants
+    Alpha,
+
+    /// force constants in SPECTRO's internal coordinates, compared side by
+    /// side
+    Fc,
+
+    /// root-mean-square amplitudes of the bonds, added to the curvilinear
+    /// coordinate table
+    Amplitudes,
+
+    /// descriptions of the modes from the potential energy distribution,
+    /// added to the vibrational frequency table
+    Ped,
+
+    /// Coriolis zeta constants larger than [ZETA_THRESH]
+    Zeta,
+
+    /// rotational constants of the overtones and combination states, added
+    /// to the rotational constant table
+    StateRots,
+
+    /// quartic distortion constants in the Nielsen, Wilson, and τ′
+    /// representations
+    DistReps,
+
+    /// anharmonic overtone and combination band energies
+    Overtones,
+
+    /// internuclear distance matrix at the equilibrium geometry
+    Dist,
+}
+
+/// threshold for printing Coriolis zeta constants, taken from the default
+/// ZTOL in SPECTRO's resonance testing
+pub const ZETA_THRESH: f64 = 0.25;
+
+/// minimum fractional contribution to the PED of a mode for a coordinate to
+/// be included in its description
+const PED_THRESH: f64 = 0.1;
+
+/// maximum number of coordinates to include in a PED description
+const PED_MAX: usize = 3;
+
+/// width of the PED description column
+const DESC: usize = 40;
+
+/// short description of `curvil` like `C1-H3 str`, using the atom labels from
+/// `sum`
+pub(crate) fn curvil_desc(sum: &Summary, curvil: &Curvil) -> String {
+    let (atoms, kind) = match curvil {
+        Curvil::Bond(a, b) => (vec![a, b], "str"),
+        Curvil::Angle(a, b, c) => (vec![a, b, c], "bend"),
+        Curvil::Torsion(a, b, c, d) => (vec![a, b, c, d], "tors"),
+        Curvil::Linear(a, b, c) => (vec![a, b, c], "lin"),
+        Curvil::Opb(a, b, c, d) => (vec![a, b, c, d], "opb"),
+    };
+    let atoms: Vec<_> = atoms
+        .into_iter()
+        .map(|a| format!("{}{a}", sum.geom.atoms[*a - 1].label()))
+        .collect();
+    format!("{} {kind}", atoms.join("-"))
+}
+
+/// choose the reduction of the distortion constants in `sum` to export to
+/// other programs, preferring the one read from the output file over one
+/// derived by [Summary::fill_reduction], and the A reduction if both were read
+pub(crate) fn preferred_reduction(sum: &Summary) -> Option<Reduction> {
+    let d = &sum.deltas;
+    match (d.has_a(), d.has_s(), sum.derived_reduction) {
+        (true, true, Some(Reduction::A)) => Some(Reduction::S),
+        (true, _, _) => Some(Reduction::A),
+        (false, true, _) => Some(Reduction::S),
+        (false, false, _) => None,
+    }
 }
 
 pub trait Format
@@ -33,6 +129,9 @@ where
     fn len(&self) -> usize;
     fn names(&self) -> &[String];
 
+    /// the optional tables to print after the standard ones
+    fn extras(&self) -> &[Extra];
+
     /// return the label for the harmonic frequencies. idx starts at 1
     fn omega(&self, idx: usize) -> String {
         format!("w{idx:<2}")
@@ -82,6 +181,15 @@ where
                 "\nQuartic and Sextic Distortion \
 		 Constants in the Watson S-Reduced Hamiltonian:\n",
             ),
+            TableType::Nielsen => String::from(
+                "\nQuartic Distortion Constants in Nielsen's Representation:\n",
+            ),
+            TableType::Wilson => String::from(
+                "\nQuartic Distortion Constants in Wilson's Representation:\n",
+            ),
+            TableType::TauPrime => {
+                String::from("\nPlanar Quartic Distortion Constants (tau'):\n")
+            }
             TableType::Curvil => {
                 let name = &self.names()[n];
                 format!(
@@ -102,6 +210,52 @@ where
                     name, "Modes", "Axes",
                 )
             }
+            TableType::Darling => {
+                let name = &self.names()[n];
+                format!(
+                    "Darling-Dennison resonances for {}:
+
+{:>16}{:>10}",
+                    name, "Modes", "K",
+                )
+            }
+            TableType::Xmat => {
+                let name = &self.names()[n];
+                format!("Anharmonic Constants for {name} (in cm-1):\n")
+            }
+            TableType::Alpha => {
+                let name = &self.names()[n];
+                format!(
+                    "Vibration-Rotation Interaction Constants for {name} \
+		     (in MHz):\n"
+                )
+            }
+            TableType::Zeta => {
+                let name = &self.names()[n];
+                format!(
+                    "Coriolis zeta constants for {} (|zeta| > {}):
+
+{:>16}{:>6}{:>10}",
+                    name, ZETA_THRESH, "Modes", "Axis", "Zeta",
+                )
+            }
+            TableType::Overtones => {
+                let name = &self.names()[n];
+                format!(
+                    "Overtones and Combination Bands for {} (in cm-1):
+
+{:>16}{:>10}",
+                    name, "State", "Energy",
+                )
+            }
+            TableType::Dist => {
+                let name = &self.names()[n];
+                format!("Internuclear Distances for {name} (in Å):\n")
+            }
+            TableType::Fc => String::from(
+                "Force Constants in Internal Coordinates (numbered as in the \
+                 SPECTRO output):\n",
+            ),
         }
     }
 
@@ -116,6 +270,22 @@ where
         format!("{}{:<5}{}", c, sub, self.sep())
     }
 
+    /// the label for the inertia defect row in the rotational constant table
+    fn inertia_defect_label(&self) -> &'static str {
+        "Delta"
+    }
+
+    /// the units of the inertia defect
+    fn inertia_unit(&self) -> &'static str {
+        "amu Å²"
+    }
+
+    /// combine the constant `c` and the state `label` into a single
+    /// rotational constant like A(2v5), followed by `Self::SEP`
+    fn state_rot_const(&self, c: &str, label: &str) -> String {
+        format!("{:<6}{}", format!("{c}({label})"), self.sep())
+    }
+
     /// returns the labels for the quartic distortion constants (deltas) in the
     /// order DeltaJ, DeltaK, DeltaJK, deltaJ, deltaK, DJ, DJK, DK, d1, d2
     fn delta_labels(&self) -> [&'static str; 11] {
@@ -125,6 +295,26 @@ where
         ]
     }
 
+    /// returns the labels for the quartic distortion constants in Nielsen's
+    /// representation in the order DJ, DJK, DK, dJ, R5, R6. Wilson's DJ, DJK,
+    /// and DK share the first three labels
+    fn nielsen_labels(&self) -> [&'static str; 6] {
+        ["D J", "D JK", "D K", "d J", "R 5", "R 6"]
+    }
+
+    /// returns the labels for the planar quartic distortion constants (τ′) in
+    /// the order aaaa, bbbb, cccc, aabb, aacc, bbcc
+    fn tau_labels(&self) -> [&'static str; 6] {
+        [
+            "tau' aaaa",
+            "tau' bbbb",
+            "tau' cccc",
+            "tau' aabb",
+            "tau' aacc",
+            "tau' bbcc",
+        ]
+    }
+
     /// returns the labels for the sextic distortion constants (phis) in the
     /// order PhiJ, PhiK, PhiJK, PhiKJ, phiJ, phiJK, phiK, HJ, HJK, HKJ, HK, h1,
     /// h2, h3
@@ -135,6 +325,12 @@ where
         ]
     }
 
+    /// returns the labels for the vibration-rotation interaction constants in
+    /// the order alphaA, alphaB, alphaC
+    fn alpha_labels(&self) -> [&'static str; 3] {
+        ["alpha A", "alpha B", "alpha C"]
+    }
+
     /// helper method for writing the header for the distortion constant tables
     fn dist_header(
         &self,
@@ -178,21 +374,22 @@ where
         let max_harms = self.max_harms();
         let max_corrs = self.max_corrs();
         let nsum = self.len();
-        // 4 for w/v label, 6 for each symmetry label, and 8 for each frequency
-        let dashes = Self::line(4 + 16 * nsum);
+        let desc = self.extras().contains(&Extra::Ped);
+        // 4 for w/v label, 6 for each symmetry label, and 8 for each
+        // frequency, plus the optional description
+        let width = 16 + if desc { DESC } else { 0 };
+        let dashes = Self::line(4 + width * nsum);
+        let per = if desc { 3 } else { 2 };
 
-        writeln!(f, "{}", self.pre_table(TableType::Vib, 1 + 2 * nsum))?;
+        writeln!(f, "{}", self.pre_table(TableType::Vib, 1 + per * nsum))?;
 
         write!(f, "{}Mode{}", self.pre(), self.sep())?;
         for i in 0..nsum {
-            write!(
-                f,
-                "{:>8}{}{:>8}{}",
-                "Symm.",
-                Self::SEP,
-                "Freq.",
-                self.end(i < nsum - 1)
-            )?;
+            write!(f, "{:>8}{}{:>8}", "Symm.", Self::SEP, "Freq.")?;
+            if desc {
+                write!(f, "{}  {:<DESC$}", self.sep(), "Description")?;
+            }
+            write!(f, "{}", self.end(i < nsum - 1))?;
         }
         writeln!(f, "\n{dashes}")?;
 
@@ -202,38 +399,32 @@ where
                 if let Some(v) = sum.harm.get(i) {
                     write!(
                         f,
-                        "{:>8}{}{:8.1}{}",
+                        "{:>8}{}{:8.1}",
                         self.irrep(
                             sum.irreps.get(i).unwrap_or(&symm::Irrep::A),
                         ),
                         self.sep(),
                         v,
-                        self.end(j < nsum - 1)
                     )?;
                 } else {
-                    write!(
-                        f,
-                        "{:8}{}{:8}{}",
-                        "",
-                        self.sep(),
-                        "",
-                        self.end(j < nsum - 1)
-                    )?;
+                    write!(f, "{:8}{}{:8}", "", self.sep(), "")?;
+                }
+                if desc {
+                    let d = self.ped_desc(sum, i);
+                    write!(f, "{}  {d:<DESC$}", self.sep())?;
                 }
+                write!(f, "{}", self.end(j < nsum - 1))?;
             }
             writeln!(f)?;
         }
         writeln!(f, "{dashes}")?;
         write!(f, "{} ZPT{}", self.pre(), self.sep())?;
         for (i, sum) in self.into_iter().enumerate() {
-            write!(
-                f,
-                "{:8}{}{:8.1}{}",
-                "",
-                self.sep(),
-                sum.zpt,
-                self.end(i < nsum - 1)
-            )?;
+            write!(f, "{:8}{}{:8.1}", "", self.sep(), sum.zpt)?;
+            if desc {
+                write!(f, "{}  {:DESC$}", self.sep(), "")?;
+            }
+            write!(f, "{}", self.end(i < nsum - 1))?;
         }
         writeln!(f)?;
 
@@ -243,24 +434,21 @@ where
                 if let Some(v) = sum.corr.get(i) {
                     write!(
                         f,
-                        "{:>8}{}{:8.1}{}",
+                        "{:>8}{}{:8.1}",
                         self.irrep(
                             sum.irreps.get(i).unwrap_or(&symm::Irrep::A),
                         ),
                         self.sep(),
                         v,
-                        self.end(j < nsum - 1)
                     )?;
                 } else {
-                    write!(
-                        f,
-                        "{:8}{}{:8}{}",
-                        "",
-                        self.sep(),
-                        "",
-                        self.end(j < nsum - 1)
-                    )?;
+                    write!(f, "{:8}{}{:8}", "", self.sep(), "")?;
                 }
+                if desc {
+                    let d = self.ped_desc(sum, i);
+                    write!(f, "{}  {d:<DESC$}", self.sep())?;
+                }
+                write!(f, "{}", self.end(j < nsum - 1))?;
             }
             writeln!(f)?;
         }
@@ -273,7 +461,38 @@ where
         &self,
         f: &mut std::fmt::Formatter,
     ) -> Result<(), std::fmt::Error> {
-        let nsum = self.len();
+        // isotopologues get their own columns after all of the parents
+        let isos: Vec<_> = self
+            .into_iter()
+            .zip(self.names())
+            .filter_map(|(sum, name)| {
+                let iso = sum.isotopologue.as_ref()?;
+                let col = Summary {
+                    geom: sum.geom.clone(),
+                    masses: iso.masses.clone(),
+                    rotor: iso.rotor,
+                    rot_equil: iso.rot_equil.clone(),
+                    rots: if iso.rot0.is_empty() {
+                        vec![]
+                    } else {
+                        vec![iso.rot0.clone()]
+                    },
+                    ..Default::default()
+                };
+                Some((col, format!("{name} ({})", iso.label())))
+            })
+            .collect();
+        let cols: Vec<&Summary> = self
+            .into_iter()
+            .chain(isos.iter().map(|(s, _)| s))
+            .collect();
+        let names: Vec<&str> = self
+            .names()
+            .iter()
+            .map(String::as_str)
+            .chain(isos.iter().map(|(_, n)| n.as_str()))
+            .collect();
+        let nsum = cols.len();
         write!(f, "{}", self.pre_table(TableType::Rot, 1 + nsum))?;
         write!(
             f,
@@ -284,8 +503,7 @@ where
             self.sep()
         )?;
         for i in 0..nsum {
-            write!(f, r"{:>15}{}", self.names()[i], self.end(i < nsum - 1))
-                .unwrap();
+            write!(f, r"{:>15}{}", names[i], self.end(i < nsum - 1)).unwrap();
         }
         writeln!(f)?;
 
@@ -306,27 +524,8 @@ where
                 "MHz",
                 self.sep(),
             )?;
-            for (i, sum) in self.into_iter().enumerate() {
-                // linear molecule - only have B
-                let rot = if sum.rot_equil.len() == 1 {
-                    if j == 1 {
-                        sum.rot_equil.first()
-                    } else {
-                        None
-                    }
-                } else if sum.rot_equil.len() == 2 {
-                    // symmetric top - only have A and C
-                    if j == 0 {
-                        sum.rot_equil.first()
-                    } else if j == 2 {
-                        sum.rot_equil.last()
-                    } else {
-                        None
-                    }
-                } else {
-                    sum.rot_equil.get(j)
-                };
-                if let Some(rot) = rot {
+            for (i, sum) in cols.iter().enumerate() {
+                if let Some(rot) = sum.rotor.abc(&sum.rot_equil, j) {
                     write!(
                         f,
                         "{:WIDTH$.PREC$}{}",
@@ -351,26 +550,9 @@ where
                     "MHz",
                     self.sep(),
                 )?;
-                for (k, sum) in self.into_iter().enumerate() {
+                for (k, sum) in cols.iter().enumerate() {
                     if let Some(rot) = sum.rots.get(i) {
-                        let abc = if rot.len() == 1 {
-                            if j == 1 {
-                                rot.first()
-                            } else {
-                                None
-                            }
-                        } else if rot.len() == 2 {
-                            if j == 0 {
-                                rot.first()
-                            } else if j == 2 {
-                                rot.last()
-                            } else {
-                                None
-                            }
-                        } else {
-                            rot.get(j)
-                        };
-                        if let Some(abc) = abc {
+                        if let Some(abc) = sum.rotor.abc(rot, j) {
                             write!(
                                 f,
                                 "{:WIDTH$.PREC$}{}",
@@ -398,6 +580,63 @@ where
             }
         }
 
+        if self.extras().contains(&Extra::StateRots) {
+            // collect the multiply-excited states from every summary
+            let mut states: Vec<&Vec<usize>> = Vec::new();
+            for sum in cols.iter() {
+                for key in sum.state_rots.keys() {
+                    if key.iter().sum::<usize>() > 1 && !states.contains(&key) {
+                        states.push(key);
+                    }
+                }
+            }
+            states.sort_by(|a, b| {
+                let (sa, sb): (usize, usize) = (a.iter().sum(), b.iter().sum());
+                sa.cmp(&sb).then(b.cmp(a))
+            });
+            for quanta in states {
+                let modes: Vec<_> = quanta
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, q)| **q > 0)
+                    .map(|(i, q)| (i + 1, *q))
+                    .collect();
+                let label = ResonancePolyad::state_label(&modes);
+                for j in 0..3 {
+                    write!(
+                        f,
+                        "{}{}{:>8}{}",
+                        self.pre(),
+                        self.state_rot_const(["A", "B", "C"][j], &label),
+                        "MHz",
+                        self.sep(),
+                    )?;
+                    for (k, sum) in cols.iter().enumerate() {
+                        let abc = sum
+                            .state_rots
+                            .get(quanta)
+                            .and_then(|rot| sum.rotor.abc(rot, j));
+                        if let Some(abc) = abc {
+                            write!(
+                                f,
+                                "{:WIDTH$.PREC$}{}",
+                                abc,
+                                self.end(k < nsum - 1)
+                            )?;
+                        } else {
+                            write!(
+                                f,
+                                "{:WIDTH$}{}",
+                                "",
+                                self.end(k < nsum - 1)
+                            )?;
+                        }
+                    }
+                    writeln!(f)?;
+                }
+            }
+        }
+
         // this is kappa for the vibrationally-averaged rotational constants
         write!(
             f,
@@ -408,10 +647,12 @@ where
             "",
             self.sep()
         )?;
-        for (i, sum) in self.into_iter().enumerate() {
-            if sum.rot_equil.len() == 3 {
-                let r = &sum.rots[0];
-                let (a, b, c) = (r[0], r[1], r[2]);
+        for (i, sum) in cols.iter().enumerate() {
+            let r = sum
+                .rots
+                .first()
+                .filter(|_| sum.rotor == RotorType::Asymmetric);
+            if let Some(&[a, b, c]) = r.map(Vec::as_slice) {
                 let k = (2.0 * b - a - c) / (a - c);
                 write!(f, "{k:WIDTH$.7}")?;
             } else {
@@ -421,6 +662,29 @@ where
         }
         writeln!(f)?;
 
+        // and the inertia defect for the same constants, which is only
+        // defined for asymmetric tops
+        if cols.iter().any(|sum| sum.rotor == RotorType::Asymmetric) {
+            write!(
+                f,
+                "{}{:<6}{}{:>8}{}",
+                self.pre(),
+                self.inertia_defect_label(),
+                self.sep(),
+                self.inertia_unit(),
+                self.sep()
+            )?;
+            for (i, sum) in cols.iter().enumerate() {
+                if let Some(Some(d)) = sum.inertia_defects().first() {
+                    write!(f, "{d:WIDTH$.4}")?;
+                } else {
+                    write!(f, "{:WIDTH$}", "")?;
+                }
+                write!(f, "{}", self.end(i < nsum - 1))?;
+            }
+            writeln!(f)?;
+        }
+
         writeln!(f, "{}", self.post_table())?;
 
         Ok(())
@@ -438,7 +702,7 @@ where
 
         let delta_labels = self.delta_labels();
         write_dist_consts! {
-            f, self, deltas,
+            f, self, deltas[Some(Reduction::A)],
             big_delta_j => delta_labels[0],
             big_delta_k => delta_labels[1],
             big_delta_jk => delta_labels[2],
@@ -450,7 +714,7 @@ where
 
         let phi_labels = self.phi_labels();
         write_dist_consts! {
-            f, self, phis,
+            f, self, phis[Some(Reduction::A)],
             big_phi_j =>  phi_labels[0],
             big_phi_k =>  phi_labels[1],
             big_phi_jk =>  phi_labels[2],
@@ -466,7 +730,7 @@ where
         self.dist_header(nsum, f, &dashes)?;
 
         write_dist_consts! {
-            f, self, deltas,
+            f, self, deltas[Some(Reduction::S)],
             d_j => delta_labels[5],
             d_jk => delta_labels[6],
             d_k => delta_labels[7],
@@ -478,7 +742,7 @@ where
         writeln!(f, "{dashes}")?;
 
         write_dist_consts! {
-            f, self, phis,
+            f, self, phis[Some(Reduction::S)],
             h_j =>  phi_labels[7],
             h_jk =>  phi_labels[8],
             h_kj =>  phi_labels[9],
@@ -494,31 +758,137 @@ where
         Ok(())
     }
 
+    /// print the quartic distortion constants in the Nielsen, Wilson, and τ′
+    /// representations, each in its own table
+    fn print_dist_reps(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        let nsum = self.len();
+        let dashes = Self::line(13 + 8 + 10 * nsum);
+        let nielsen_labels = self.nielsen_labels();
+
+        writeln!(f, "{}", self.pre_table(TableType::Nielsen, 1 + nsum))?;
+        self.dist_header(nsum, f, &dashes)?;
+        write_dist_consts! {
+            f, self, nielsen,
+            d_j => nielsen_labels[0],
+            d_jk => nielsen_labels[1],
+            d_k => nielsen_labels[2],
+            delta_j => nielsen_labels[3],
+            r5 => nielsen_labels[4],
+            r6 => nielsen_labels[5],
+        }
+        writeln!(f, "{}\n", self.post_table())?;
+
+        writeln!(f, "{}", self.pre_table(TableType::Wilson, 1 + nsum))?;
+        self.dist_header(nsum, f, &dashes)?;
+        write_dist_consts! {
+            f, self, wilson,
+            d_j => nielsen_labels[0],
+            d_jk => nielsen_labels[1],
+            d_k => nielsen_labels[2],
+        }
+        writeln!(f, "{}\n", self.post_table())?;
+
+        let tau_labels = self.tau_labels();
+        writeln!(f, "{}", self.pre_table(TableType::TauPrime, 1 + nsum))?;
+        self.dist_header(nsum, f, &dashes)?;
+        write_dist_consts! {
+            f, self, tau_prime,
+            aaaa => tau_labels[0],
+            bbbb => tau_labels[1],
+            cccc => tau_labels[2],
+            aabb => tau_labels[3],
+            aacc => tau_labels[4],
+            bbcc => tau_labels[5],
+        }
+        writeln!(f, "{}\n", self.post_table())?;
+
+        Ok(())
+    }
+
     fn curvil_label(&self, curvil: &Curvil, i: usize) -> String;
 
+    /// the percent sign, for escaping in LaTeX
+    fn percent(&self) -> &'static str {
+        "%"
+    }
+
+    /// describe mode `i` of `sum` by its largest contributions to the
+    /// potential energy distribution, like `49% C2-H4 str + 49% C3-H5 str`.
+    /// when the rows of the PED are not the curvilinear coordinates, the
+    /// contributions are labeled by SPECTRO's coordinate numbers instead, like
+    /// `36% coord. 3`
+    fn ped_desc(&self, sum: &Summary, i: usize) -> String {
+        let Some(harm) = sum.harm.get(i) else {
+            return String::new();
+        };
+        let curvils = sum.ped.matches_curvils(sum.curvils.len());
+        sum.ped
+            .contributions(*harm)
+            .into_iter()
+            .filter(|(_, p)| *p >= PED_THRESH)
+            .take(PED_MAX)
+            .map(|(c, p)| {
+                let coord = match sum.curvils.get(c) {
+                    Some(curvil) if curvils => curvil_desc(sum, curvil),
+                    _ => format!("coord. {}", c + 1),
+                };
+                format!("{:.0}{} {coord}", 100.0 * p, self.percent())
+            })
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+
+    /// return the sorted temperatures of the mean square amplitudes in `sum`
+    /// if [Extra::Amplitudes] was requested, or an empty Vec otherwise
+    fn msa_temps(&self, sum: &Summary) -> Vec<usize> {
+        if !self.extras().contains(&Extra::Amplitudes) {
+            return Vec::new();
+        }
+        let mut temps: Vec<_> = sum.msa.keys().copied().collect();
+        temps.sort_unstable();
+        temps
+    }
+
     fn print_curvils(
         &self,
         f: &mut std::fmt::Formatter,
     ) -> Result<(), std::fmt::Error> {
         const FIRST: usize = 21;
         const AFTER: usize = 18;
-        let dashes = Self::line(FIRST + AFTER * 2);
         for (i, sum) in self.into_iter().enumerate() {
+            let temps = self.msa_temps(sum);
+            let ntemp = temps.len();
+            let rg = !sum.rg.is_empty();
+            let dashes =
+                Self::line(FIRST + AFTER * (2 + usize::from(rg) + ntemp));
             writeln!(f, "{}", self.pre_table(TableType::Curvil, i))?;
-            writeln!(
+            write!(
                 f,
-                "{}{:^FIRST$}{}{:>AFTER$}{}{:>AFTER$}{}",
+                "{}{:^FIRST$}{}{:>AFTER$}{}",
                 self.pre(),
                 "Coord.",
                 self.sep(),
                 "Equil.",
                 self.sep(),
-                "Vib. Avg.",
-                self.end(false)
             )?;
-            writeln!(f, "{dashes}")?;
-            let vals = sum.requil.iter().zip(&sum.ralpha);
-            for (curvil, (alpha, equil)) in sum.curvils.iter().zip(vals) {
+            if rg {
+                write!(f, "{:>AFTER$}{}", "R(g)", self.sep())?;
+            }
+            write!(f, "{:>AFTER$}{}", "Vib. Avg.", self.end(ntemp > 0))?;
+            for (k, t) in temps.iter().enumerate() {
+                let head = format!("RMS {t} K");
+                write!(f, "{head:>AFTER$}{}", self.end(k < ntemp - 1))?;
+            }
+            writeln!(f, "\n{dashes}")?;
+            let rms: Vec<_> =
+                temps.iter().map(|t| sum.rms_amplitudes(*t)).collect();
+            // SPECTRO only prints the equilibrium and vibrationally averaged
+            // values for some coordinates, such as the bonds and angles, so
+            // leave the cells for the rest blank
+            for (j, curvil) in sum.curvils.iter().enumerate() {
                 write!(
                     f,
                     "{}{:FIRST$}{}",
@@ -531,17 +901,38 @@ where
                     Curvil::Bond(_, _) => (AFTER, 5),
                     Curvil::Angle(_, _, _) => (AFTER, 3),
                     Curvil::Linear(_, _, _) => (AFTER - 2, 3),
-                    Curvil::Torsion(_, _, _, _) => todo!(),
-                    Curvil::Opb(_, _, _, _) => todo!(),
+                    Curvil::Torsion(_, _, _, _) | Curvil::Opb(_, _, _, _) => {
+                        (AFTER - 7, 3)
+                    }
                 };
-                writeln!(
-                    f,
-                    "{:width$.prec$}{}{:AFTER$.prec$}{}",
-                    equil,
-                    self.sep(),
-                    alpha,
-                    self.end(false)
-                )?;
+                match sum.requil.get(j) {
+                    Some(equil) => write!(f, "{equil:width$.prec$}")?,
+                    None => write!(f, "{:width$}", "")?,
+                }
+                write!(f, "{}", self.sep())?;
+                if rg {
+                    match sum.rg.get(j) {
+                        Some(v) => write!(f, "{v:AFTER$.prec$}")?,
+                        None => write!(f, "{:AFTER$}", "")?,
+                    }
+                    write!(f, "{}", self.sep())?;
+                }
+                match sum.ralpha.get(j) {
+                    Some(alpha) => write!(f, "{alpha:AFTER$.prec$}")?,
+                    None => write!(f, "{:AFTER$}", "")?,
+                }
+                write!(f, "{}", self.end(ntemp > 0))?;
+                for (k, amps) in rms.iter().enumerate() {
+                    // only bonds, since the angles are in radians
+                    match (curvil, amps.get(j).copied().flatten()) {
+                        (Curvil::Bond(_, _), Some(v)) => {
+                            write!(f, "{v:AFTER$.5}")?
+                        }
+                        _ => write!(f, "{:AFTER$}", "")?,
+                    }
+                    write!(f, "{}", self.end(k < ntemp - 1))?;
+                }
+                writeln!(f)?;
             }
             writeln!(f, "{}\n", self.post_table())?;
         }
@@ -620,6 +1011,317 @@ where
 
         Ok(())
     }
+
+    fn print_zeta(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        let dashes = Self::line(32);
+        for (i, sum) in self.into_iter().enumerate() {
+            writeln!(f, "{}", self.pre_table(TableType::Zeta, i))?;
+            writeln!(f, "{dashes}")?;
+            let n = sum.zeta.iter().map(Vec::len).max().unwrap_or(0);
+            for a in 0..n {
+                for b in a + 1..n {
+                    for (axis, zeta) in sum.zeta.iter().enumerate() {
+                        let Some(z) = zeta.get(a).and_then(|row| row.get(b))
+                        else {
+                            continue;
+                        };
+                        if z.abs() <= ZETA_THRESH {
+                            continue;
+                        }
+                        writeln!(
+                            f,
+                            "{}{} = {}  {}{:>4}{}{z:10.3}{}",
+                            self.pre(),
+                            self.omega(a + 1),
+                            self.omega(b + 1),
+                            self.sep(),
+                            ["x", "y", "z"][axis],
+                            self.sep(),
+                            self.end(false),
+                        )?;
+                    }
+                }
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_overtones(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        let dashes = Self::line(26);
+        for (i, sum) in self.into_iter().enumerate() {
+            writeln!(f, "{}", self.pre_table(TableType::Overtones, i))?;
+            writeln!(f, "{dashes}")?;
+            for state in &sum.vib_states {
+                if !state.is_overtone() && !state.is_combination() {
+                    continue;
+                }
+                writeln!(
+                    f,
+                    "{}{:>16}{}{:10.1}{}",
+                    self.pre(),
+                    ResonancePolyad::state_label(&state.modes()),
+                    self.sep(),
+                    state.zpt_rel,
+                    self.end(false),
+                )?;
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_dd(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        let dashes = Self::line(26);
+        for (i, sum) in self.into_iter().enumerate() {
+            if sum.darling_dennison.data.is_empty() {
+                continue;
+            }
+            writeln!(f, "{}", self.pre_table(TableType::Darling, i))?;
+            writeln!(f, "{dashes}")?;
+            let mut keys: Vec<_> = sum.darling_dennison.data.keys().collect();
+            keys.sort_unstable();
+            for c in keys {
+                let (a, b) = c;
+                write!(
+                    f,
+                    "{}2{} = 2{}  {}",
+                    self.pre(),
+                    self.omega(*a),
+                    self.omega(*b),
+                    self.sep()
+                )?;
+                if let Some(k) = sum.darling_dennison.data[c] {
+                    write!(f, "{k:10.3}")?;
+                } else {
+                    write!(f, "{:10}", "")?;
+                }
+                writeln!(f, "{}", self.end(false))?;
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_xmat(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        const WIDTH: usize = 9;
+        for (i, sum) in self.into_iter().enumerate() {
+            let n = sum.xmat.len();
+            if n == 0 {
+                continue;
+            }
+            writeln!(f, "{}", self.pre_table(TableType::Xmat, i))?;
+            let dashes = Self::line(5 + WIDTH * n);
+            write!(f, "{}{:>5}{}", self.pre(), "", self.sep())?;
+            for j in 0..n {
+                write!(f, "{:>WIDTH$}{}", j + 1, self.end(j < n - 1))?;
+            }
+            writeln!(f, "\n{dashes}")?;
+            // only print the lower triangle
+            for (j, row) in sum.xmat.iter().enumerate() {
+                write!(f, "{}{:>5}{}", self.pre(), j + 1, self.sep())?;
+                for (k, x) in row.iter().enumerate() {
+                    if k <= j {
+                        write!(f, "{x:WIDTH$.3}")?;
+                    } else {
+                        write!(f, "{:WIDTH$}", "")?;
+                    }
+                    write!(f, "{}", self.end(k < n - 1))?;
+                }
+                writeln!(f)?;
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_dist_matrix(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        const WIDTH: usize = 11;
+        for (i, sum) in self.into_iter().enumerate() {
+            let n = sum.dist.len();
+            if n == 0 {
+                continue;
+            }
+            let labels: Vec<_> = sum
+                .geom
+                .atoms
+                .iter()
+                .enumerate()
+                .map(|(j, atom)| format!("{}{}", atom.label(), j + 1))
+                .collect();
+            writeln!(f, "{}", self.pre_table(TableType::Dist, i))?;
+            let dashes = Self::line(5 + WIDTH * n);
+            write!(f, "{}{:>5}{}", self.pre(), "", self.sep())?;
+            for (j, label) in labels.iter().enumerate().take(n) {
+                write!(f, "{label:>WIDTH$}{}", self.end(j < n - 1))?;
+            }
+            writeln!(f, "\n{dashes}")?;
+            // only print the lower triangle
+            for (j, row) in sum.dist.iter().enumerate() {
+                write!(f, "{}{:>5}{}", self.pre(), labels[j], self.sep())?;
+                for (k, d) in row.iter().enumerate() {
+                    if k <= j {
+                        write!(f, "{d:WIDTH$.5}")?;
+                    } else {
+                        write!(f, "{:WIDTH$}", "")?;
+                    }
+                    write!(f, "{}", self.end(k < n - 1))?;
+                }
+                writeln!(f)?;
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_alphas(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        const WIDTH: usize = 12;
+        let dashes = Self::line(6 + WIDTH * 3);
+        for (i, sum) in self.into_iter().enumerate() {
+            if sum.alphas.is_empty() {
+                continue;
+            }
+            writeln!(f, "{}", self.pre_table(TableType::Alpha, i))?;
+            write!(f, "{}{:<6}{}", self.pre(), "Mode", self.sep())?;
+            for (j, label) in self.alpha_labels().iter().enumerate() {
+                write!(f, "{label:>WIDTH$}{}", self.end(j < 2))?;
+            }
+            writeln!(f, "\n{dashes}")?;
+            for (j, row) in sum.alphas.iter().enumerate() {
+                // skip modes not reported by spectro, like the second
+                // component of a degenerate pair
+                if row.iter().all(|a| *a == 0.0) {
+                    continue;
+                }
+                write!(f, "{}{:<6}{}", self.pre(), self.nu(j + 1), self.sep())?;
+                for (k, a) in row.iter().enumerate() {
+                    // similarly, leave out axes that were not reported
+                    if *a == 0.0 {
+                        write!(f, "{:WIDTH$}", "")?;
+                    } else {
+                        write!(f, "{a:WIDTH$.3}")?;
+                    }
+                    write!(f, "{}", self.end(k < 2))?;
+                }
+                writeln!(f)?;
+            }
+            writeln!(f, "{}\n", self.post_table())?;
+        }
+
+        Ok(())
+    }
+
+    /// print the internal-coordinate force constants of every summary side by
+    /// side, followed by their difference if there are exactly two summaries
+    fn print_fcs(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        const FIRST: usize = 14;
+        const WIDTH: usize = 14;
+        let mut keys: Vec<_> = self
+            .into_iter()
+            .flat_map(|sum| sum.internal_fcs.keys())
+            .collect();
+        if keys.is_empty() {
+            return Ok(());
+        }
+        keys.sort_unstable_by(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+        keys.dedup();
+
+        let nsum = self.len();
+        let diff = nsum == 2;
+        let ncol = nsum + usize::from(diff);
+        writeln!(f, "{}", self.pre_table(TableType::Fc, 1 + ncol))?;
+        write!(f, "{}{:<FIRST$}{}", self.pre(), "Coords", self.sep())?;
+        for (i, name) in self.names().iter().enumerate() {
+            write!(f, "{name:>WIDTH$}{}", self.end(i < ncol - 1))?;
+        }
+        if diff {
+            write!(f, "{:>WIDTH$}{}", "Diff", self.end(false))?;
+        }
+        writeln!(f, "\n{}", Self::line(FIRST + WIDTH * ncol))?;
+
+        for key in keys {
+            let label: Vec<_> = key.iter().map(|k| k.to_string()).collect();
+            write!(
+                f,
+                "{}{:<FIRST$}{}",
+                self.pre(),
+                label.join(" "),
+                self.sep()
+            )?;
+            let vals: Vec<_> = self
+                .into_iter()
+                .map(|sum| sum.internal_fcs.get(key).flatten())
+                .collect();
+            for (i, v) in vals.iter().enumerate() {
+                if let Some(v) = v {
+                    write!(f, "{v:WIDTH$.5}")?;
+                } else {
+                    write!(f, "{:WIDTH$}", "")?;
+                }
+                write!(f, "{}", self.end(i < ncol - 1))?;
+            }
+            if diff {
+                if let [Some(a), Some(b)] = vals[..] {
+                    write!(f, "{:WIDTH$.5}", b - a)?;
+                } else {
+                    write!(f, "{:WIDTH$}", "")?;
+                }
+                write!(f, "{}", self.end(false))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{}\n", self.post_table())?;
+
+        Ok(())
+    }
+
+    /// print the optional tables requested in [Format::extras]
+    fn print_extras(
+        &self,
+        f: &mut std::fmt::Formatter,
+    ) -> Result<(), std::fmt::Error> {
+        for extra in self.extras() {
+            match extra {
+                Extra::Xmat => self.print_xmat(f)?,
+                Extra::Alpha => self.print_alphas(f)?,
+                Extra::Fc => self.print_fcs(f)?,
+                Extra::Zeta => self.print_zeta(f)?,
+                Extra::Overtones => self.print_overtones(f)?,
+                Extra::DistReps => self.print_dist_reps(f)?,
+                Extra::Dist => self.print_dist_matrix(f)?,
+                // printed as part of other tables
+                Extra::Amplitudes | Extra::Ped | Extra::StateRots => {}
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// implement [std::fmt::Display] for a type that implements [Format]
@@ -634,6 +1336,8 @@ macro_rules! impl_display {
                 self.print_curvils(f)?;
                 self.print_fermi(f)?;
                 self.print_coriol(f)?;
+                self.print_dd(f)?;
+                self.print_extras(f)?;
 
                 Ok(())
             }
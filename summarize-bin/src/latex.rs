@@ -1,13 +1,14 @@
 use summarize::{curvil::Curvil, Summary};
 
 use crate::{
-    format::{Format, TableType},
+    format::{Extra, Format, TableType},
     impl_display,
 };
 
 pub(crate) struct Latex {
     pub summaries: Vec<Summary>,
     pub names: Vec<String>,
+    pub extras: Vec<Extra>,
 }
 
 impl_display!(Latex);
@@ -78,20 +79,27 @@ impl Format for Latex {
     fn pre_table(&self, typ: TableType, cols: usize) -> String {
         match typ {
             TableType::Vib => {
-                // left align mode column followed
+                // left align mode column followed by symmetry, frequency, and
+                // optionally description columns for each summary
+                let per = if self.extras.contains(&Extra::Ped) {
+                    3
+                } else {
+                    2
+                };
+                let nsum = cols / per;
                 let mut s = String::from("l");
-                for _ in 0..cols / 2 {
-                    s.push_str("lr");
+                for _ in 0..nsum {
+                    s.push_str(&"lrl"[..per]);
                 }
-                let head = if cols / 2 > 1 {
+                let head = if nsum > 1 {
                     use std::fmt::Write;
                     let mut h = String::from("\n & ");
-                    for i in 0..cols / 2 {
+                    for i in 0..nsum {
                         write!(
                             h,
-                            r"\multicolumn{{2}}{{c}}{{{}}}{}",
+                            r"\multicolumn{{{per}}}{{c}}{{{}}}{}",
                             self.names[i],
-                            self.end(i < cols / 2 - 1)
+                            self.end(i < nsum - 1)
                         )
                         .unwrap();
                     }
@@ -120,16 +128,33 @@ impl Format for Latex {
 \begin{{tabular}}{{{s}}}",
                 )
             }
-            TableType::DistA | TableType::DistS => {
-                let cap = format!(
-                    "Quartic and sextic distortion constants in the \
+            TableType::DistA
+            | TableType::DistS
+            | TableType::Nielsen
+            | TableType::Wilson
+            | TableType::TauPrime => {
+                let cap = match typ {
+                    TableType::DistA | TableType::DistS => format!(
+                        "Quartic and sextic distortion constants in the \
 				   Watson {}-reduced Hamiltonian",
-                    if matches!(typ, TableType::DistA) {
-                        "A"
-                    } else {
-                        "S"
-                    }
-                );
+                        if matches!(typ, TableType::DistA) {
+                            "A"
+                        } else {
+                            "S"
+                        }
+                    ),
+                    TableType::Nielsen => String::from(
+                        "Quartic distortion constants in Nielsen's \
+                         representation",
+                    ),
+                    TableType::Wilson => String::from(
+                        "Quartic distortion constants in Wilson's \
+                         representation",
+                    ),
+                    _ => String::from(
+                        r"Planar quartic distortion constants ($\tau'$)",
+                    ),
+                };
                 let mut s = String::from("ll");
                 for _ in 1..cols {
                     s.push('r');
@@ -146,11 +171,16 @@ impl Format for Latex {
                     r"Curvilinear coordinates for {} (in \AA{{}} or $^\circ$)",
                     self.names[cols],
                 );
+                let sum = &self.summaries[cols];
+                let s = "r".repeat(
+                    2 + usize::from(!sum.rg.is_empty())
+                        + self.msa_temps(sum).len(),
+                );
                 format!(
                     r"\begin{{table}}
 \centering
 \caption{{{cap}}}
-\begin{{tabular}}{{lrr}}",
+\begin{{tabular}}{{l{s}}}",
                 )
             }
             TableType::Fermi => {
@@ -174,6 +204,99 @@ impl Format for Latex {
                     "Modes", "Axes"
                 )
             }
+            TableType::Darling => {
+                let cap = format!(
+                    r"Darling-Dennison resonances for {}",
+                    self.names[cols]
+                );
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{lr}}
+{:>16} & {:>10} \\",
+                    "Modes", "$K$"
+                )
+            }
+            TableType::Dist => {
+                let cap = format!(
+                    r"Internuclear distances for {} (in \AA)",
+                    self.names[cols]
+                );
+                let s = "r".repeat(self.summaries[cols].dist.len());
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{l{s}}}",
+                )
+            }
+            TableType::Xmat => {
+                let cap = format!(
+                    r"Anharmonic constants for {} (in cm$^{{-1}}$)",
+                    self.names[cols]
+                );
+                let s = "r".repeat(self.summaries[cols].xmat.len());
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{l{s}}}",
+                )
+            }
+            TableType::Alpha => {
+                let cap = format!(
+                    "Vibration-rotation interaction constants for {} (in MHz)",
+                    self.names[cols]
+                );
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{lrrr}}",
+                )
+            }
+            TableType::Overtones => {
+                let cap = format!(
+                    "Overtone and combination band energies for {} \
+                     (in cm$^{{-1}}$)",
+                    self.names[cols]
+                );
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{lr}}
+{:>16} & {:>10} \\",
+                    "State", "Energy"
+                )
+            }
+            TableType::Zeta => {
+                let cap = format!(
+                    r"Coriolis zeta constants for {} ($|\zeta| > {}$)",
+                    self.names[cols],
+                    crate::format::ZETA_THRESH,
+                );
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{lrr}}
+{:>16} & {:>4} & {:>10} \\",
+                    "Modes", "Axis", r"$\zeta$"
+                )
+            }
+            TableType::Fc => {
+                let cap = "Force constants in internal coordinates, numbered \
+                           as in the SPECTRO output";
+                let s = "r".repeat(cols - 1);
+                format!(
+                    r"\begin{{table}}
+\centering
+\caption{{{cap}}}
+\begin{{tabular}}{{l{s}}}",
+                )
+            }
         }
     }
 
@@ -182,6 +305,10 @@ impl Format for Latex {
 \end{table}"
     }
 
+    fn percent(&self) -> &'static str {
+        r"\%"
+    }
+
     fn rot_const(&self, c: &str, sub: impl std::fmt::Display) -> String {
         format!("${}_{{{:<5}}}${}", c, sub, self.sep())
     }
@@ -202,6 +329,40 @@ impl Format for Latex {
         ]
     }
 
+    fn alpha_labels(&self) -> [&'static str; 3] {
+        [r"$\alpha^{A}$", r"$\alpha^{B}$", r"$\alpha^{C}$"]
+    }
+
+    fn inertia_defect_label(&self) -> &'static str {
+        r"$\Delta$"
+    }
+
+    fn inertia_unit(&self) -> &'static str {
+        r"amu \AA$^2$"
+    }
+
+    fn nielsen_labels(&self) -> [&'static str; 6] {
+        [
+            r"$D_{J}$",
+            r"$D_{JK}$",
+            r"$D_{K}$",
+            r"$\delta_{J}$",
+            r"$R_{5}$",
+            r"$R_{6}$",
+        ]
+    }
+
+    fn tau_labels(&self) -> [&'static str; 6] {
+        [
+            r"$\tau'_{aaaa}$",
+            r"$\tau'_{bbbb}$",
+            r"$\tau'_{cccc}$",
+            r"$\tau'_{aabb}$",
+            r"$\tau'_{aacc}$",
+            r"$\tau'_{bbcc}$",
+        ]
+    }
+
     fn phi_labels(&self) -> [&'static str; 15] {
         [
             r"$\Phi_{J}$",
@@ -238,8 +399,14 @@ impl Format for Latex {
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            // pretty sure nothing else is printed in this part
-            Torsion(_, _, _, _) => todo!(),
+            Torsion(a, b, c, d) => format!(
+                "$\\tau(\\text{{{}}}_{{{a}}} - \\text{{{}}}_{{{b}}} - \
+		 \\text{{{}}}_{{{c}}} - \\text{{{}}}_{{{d}}})$",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
             Linear(a, b, c) => format!(
                 "LIN$(\\text{{{}}}_{{{a}}} - \
 		 \\text{{{}}}_{{{b}}} - \\text{{{}}}_{{{c}}})$",
@@ -247,7 +414,14 @@ impl Format for Latex {
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            Opb(_, _, _, _) => todo!(),
+            Opb(a, b, c, d) => format!(
+                "$\\gamma(\\text{{{}}}_{{{a}}} - \\text{{{}}}_{{{b}}} - \
+		 \\text{{{}}}_{{{c}}} - \\text{{{}}}_{{{d}}})$",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
         }
     }
 
@@ -266,6 +440,10 @@ impl Format for Latex {
     fn names(&self) -> &[String] {
         &self.names
     }
+
+    fn extras(&self) -> &[Extra] {
+        &self.extras
+    }
 }
 
 impl<'a> IntoIterator for &'a Latex {
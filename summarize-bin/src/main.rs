@@ -5,11 +5,18 @@ use std::{
     str::FromStr,
 };
 
-use clap::Parser;
+use clap::{ArgGroup, Parser};
 
-use summarize::{Recompute, Summary, SYMM_EPS, TO_MHZ};
+use summarize::{
+    isotope::Substitution, polyad::ResonancePolyad, reduction::Representation,
+    rot_levels, semiexp, thermo::Variant, vpt2, Recompute, Summary, SYMM_EPS,
+    TO_MHZ,
+};
 
-use crate::{csv::Csv, latex::Latex, org::Org, text::Text};
+use crate::{
+    csv::Csv, format::Extra, latex::Latex, org::Org, pgopher::Pgopher,
+    spcat::Spcat, text::Text,
+};
 
 mod tui;
 
@@ -67,19 +74,37 @@ fn find_units(vals: Vec<Option<f64>>) -> (Vec<Option<f64>>, Unit) {
     (vals, Unit::MHz)
 }
 
+/// write the distortion constants in `$struct` for each summary in `$iter`.
+/// if the optional `$red` [summarize::reduction::Reduction] is given, values
+/// in summaries whose `derived_reduction` matches it are marked with `*`
 macro_rules! write_dist_consts {
     ($w:ident, $iter: expr, $struct:ident,
+     $($field:ident => $name:expr$(,)?),*) => {
+	write_dist_consts!(
+	    $w, $iter, $struct[None::<summarize::reduction::Reduction>],
+	    $($field => $name,)*
+	)
+    };
+    ($w:ident, $iter: expr, $struct:ident[$red:expr],
      $($field:ident => $name:expr$(,)?),*) => {
 	$(
 	    let nsum = $iter.len();
 	    let vals: Vec<_> = $iter.into_iter().map(|sum| sum.$struct.$field).collect();
+	    let derived: Vec<_> = $iter
+		.into_iter()
+		.map(|sum| $red.is_some_and(|r| sum.derived_reduction == Some(r)))
+		.collect();
 	    if vals.iter().any(std::option::Option::is_some) {
 		let (vals, unit) = crate::find_units(vals);
 		write!($w, "{}{:<13}{}{:<8}{}", $iter.pre(), $name, $iter.sep(),
 		       $iter.format_dist_unit(unit), $iter.sep())?;
 		for (i, v) in vals.iter().enumerate() {
 		    if let Some(d) = v {
-			write!($w, "{:10.3}", d)?;
+			if derived[i] {
+			    write!($w, "{:9.3}*", d)?;
+			} else {
+			    write!($w, "{:10.3}", d)?;
+			}
 		    } else {
 			write!($w, "{:10.3}", "")?;
 		    }
@@ -96,6 +121,8 @@ mod csv;
 mod format;
 mod latex;
 mod org;
+mod pgopher;
+mod spcat;
 mod text;
 
 #[cfg(test)]
@@ -104,6 +131,11 @@ mod tests;
 /// summarize the output of SPECTRO
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(group(
+    ArgGroup::new("vpt2_levels")
+        .args(["vpt2", "vpt2_cutoff"])
+        .multiple(true)
+))]
 struct Args {
     /// only print the vibrational frequency summary
     #[arg(short, long, conflicts_with_all = ["tex", "json", "csv", "org"])]
@@ -150,9 +182,183 @@ struct Args {
     #[arg(short, long, default_value_t = false)]
     wavenumbers: bool,
 
+    /// print additional tables given as a comma-separated list
+    #[arg(long, value_delimiter = ',')]
+    extra: Vec<Extra>,
+
+    /// fill in the missing A- or S-reduced distortion constants by converting
+    /// the other reduction in Watson's representation REP (ir or iiir),
+    /// marking the derived values with *
+    #[arg(long, value_name = "REP")]
+    fill_reduction: Option<Representation>,
+
+    /// warn about equilibrium rotational constants that differ from the ones
+    /// computed from the geometry by more than the relative tolerance TOL
+    #[arg(long, value_name = "TOL")]
+    check_rots: Option<f64>,
+
+    /// predict the rotational constants of the isotopologue given as a
+    /// comma-separated list of substitutions like 2=13C,5=D, and print them as
+    /// extra columns in the rotational constants table
+    #[arg(long, value_delimiter = ',', value_name = "ATOM=ISOTOPE")]
+    isotope: Vec<Substitution>,
+
+    /// only print the corrected frequencies whose leading eigenvector
+    /// coefficient in a Fermi resonance polyad is below THRESH
+    #[arg(
+        long,
+        value_name = "THRESH",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org"]
+    )]
+    mixing: Option<f64>,
+
+    /// fit a semi-experimental equilibrium structure to the experimental
+    /// ground state rotational constants in REF. the first input file is the
+    /// parent molecule and each of the others should be an isotopologue with
+    /// the same geometry, and REF should contain one line of constants for
+    /// each of them in MHz, optionally followed by their uncertainties
+    #[arg(
+        long,
+        value_name = "REF",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org", "mixing"]
+    )]
+    se_fit: Option<String>,
+
+    /// write a PGOPHER input file for each summary to DIR, named after the
+    /// summary's name with a .pgo extension
+    #[arg(long, value_name = "DIR")]
+    pgopher: Option<String>,
+
+    /// write SPFIT/SPCAT .par, .var, and .int files for the ground state of
+    /// each summary to DIR, named after the summary's name
+    #[arg(long, value_name = "DIR")]
+    spcat: Option<String>,
+
+    /// with --spcat, write a set of files for every vibrational state with
+    /// rotational constants instead of just the ground state, with _vN
+    /// appended to the file names
+    #[arg(long, requires = "spcat")]
+    spcat_states: bool,
+
+    /// print the ground state rotational energy levels up to J = JMAX and the
+    /// a-, b-, and c-type transitions between them, computed from the A- or
+    /// S-reduced Hamiltonian
+    #[arg(
+        long,
+        value_name = "JMAX",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org", "mixing"]
+    )]
+    levels: Option<usize>,
+
+    /// with --levels, set up the Hamiltonian in Watson's representation REP
+    /// (ir or iiir) instead of Ir
+    #[arg(long, value_name = "REP", requires = "levels")]
+    rep: Option<Representation>,
+
+    /// print the translational, rotational, and vibrational contributions to
+    /// the partition function, enthalpy, entropy, and heat capacity, using
+    /// the harmonic frequencies (harm), the anharmonic fundamentals (fund), or
+    /// the anharmonic fundamentals and ZPT (anharm)
+    #[arg(
+        long,
+        value_name = "VARIANT",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org", "mixing"]
+    )]
+    thermo: Option<Variant>,
+
+    /// with --thermo, the comma-separated temperatures in K
+    #[arg(
+        long,
+        value_delimiter = ',',
+        requires = "thermo",
+        default_value = "298.15"
+    )]
+    temps: Vec<f64>,
+
+    /// with --thermo, override the rotational symmetry number from the point
+    /// group
+    #[arg(long, requires = "thermo")]
+    symmetry_number: Option<usize>,
+
+    /// print the overtones and combination bands with up to NMAX total quanta
+    /// predicted from the harmonic frequencies and anharmonic constants, along
+    /// with pairs of levels that could be in Fermi or Darling-Dennison
+    /// resonance. can be combined with or replaced by --vpt2-cutoff
+    #[arg(
+        long,
+        value_name = "NMAX",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org", "mixing"]
+    )]
+    vpt2: Option<usize>,
+
+    /// like --vpt2, but only include levels up to CUTOFF cm⁻¹ above the ZPT
+    #[arg(
+        long,
+        value_name = "CUTOFF",
+        conflicts_with_all = ["vib", "tex", "json", "csv", "org", "mixing"]
+    )]
+    vpt2_cutoff: Option<f64>,
+
+    /// with --vpt2 or --vpt2-cutoff, the largest energy difference in cm⁻¹
+    /// between two levels to flag as a possible resonance
+    #[arg(long, requires = "vpt2_levels", default_value_t = 50.0)]
+    resonance_window: f64,
+
     infiles: Vec<String>,
 }
 
+/// print the VPT2 levels of each of `summaries` with up to `nmax` quanta and
+/// energies up to `cutoff`, followed by the possible resonances within
+/// `window` cm⁻¹. at least one of `nmax` and `cutoff` should be given
+fn print_vpt2(
+    summaries: &[Summary],
+    names: &[String],
+    nmax: Option<usize>,
+    cutoff: Option<f64>,
+    window: f64,
+) {
+    for (sum, name) in summaries.iter().zip(names) {
+        let levels = match sum.vpt2_levels(nmax, cutoff) {
+            Ok(levels) => levels,
+            Err(e) => {
+                eprintln!("skipping {name}: {e}");
+                continue;
+            }
+        };
+        let labels: Vec<_> = levels
+            .iter()
+            .map(|l| ResonancePolyad::state_label(&l.state.modes()))
+            .collect();
+        println!("VPT2 levels of {name} (ZPT = {:.1} cm⁻¹):", sum.zpt);
+        println!("State                 E (cm⁻¹)    Symm");
+        for (l, label) in levels.iter().zip(&labels) {
+            let symm = match &l.symmetry {
+                Some(ir) => ir.to_string(),
+                None => String::from("?"),
+            };
+            println!("{label:<20}{:10.1}{symm:>8}", l.state.zpt_rel);
+        }
+        println!();
+        println!("Possible resonances of {name}:");
+        println!(
+            "Lower               Upper                 Δ (cm⁻¹)              \
+             Type"
+        );
+        for r in vpt2::resonances(&levels, window) {
+            let (lo, hi) = r.levels;
+            let kind = match r.kind {
+                vpt2::ResonanceType::Fermi => "Fermi",
+                vpt2::ResonanceType::DarlingDennison => "Darling-Dennison",
+            };
+            println!(
+                "{:<20}{:<20}{:10.1}{kind:>18}",
+                labels[lo], labels[hi], r.gap
+            );
+        }
+        println!();
+    }
+}
+
 fn just_vib(summaries: &Vec<Summary>) {
     for sum in summaries {
         println!("Vibrational Frequencies (cm⁻¹):");
@@ -184,6 +390,243 @@ fn just_vib(summaries: &Vec<Summary>) {
     }
 }
 
+fn mixed_states(summaries: &[Summary], names: &[String], thresh: f64) {
+    for (sum, name) in summaries.iter().zip(names) {
+        println!("Strongly-mixed states in {name} (coefficient < {thresh}):");
+        println!("{:>5}{:>8}{:>8}  {}", "Mode", "Corr", "Coeff", "Polyad");
+        for (i, p, c) in sum.mixed_states(thresh) {
+            println!(
+                "{:5}{:8.1}{:8.3}  {}",
+                i + 1,
+                sum.corr[i],
+                c,
+                sum.polyads[p].label()
+            );
+        }
+        println!();
+    }
+}
+
+/// warn about the equilibrium rotational constants in `summaries` that differ
+/// from the ones computed from their geometries by more than the relative
+/// tolerance `tol`
+fn check_rots(summaries: &[Summary], names: &[String], tol: f64) {
+    for (sum, name) in summaries.iter().zip(names) {
+        if sum.geom.atoms.is_empty() || sum.rot_equil.is_empty() {
+            continue;
+        }
+        let geom = sum.geom_rot_equil();
+        for (j, abc) in ["A", "B", "C"].iter().enumerate() {
+            let (Some(got), Some(want)) =
+                (sum.rotor.abc(&sum.rot_equil, j), sum.rotor.abc(&geom, j))
+            else {
+                continue;
+            };
+            let diff = (got - want) / want;
+            if diff.abs() > tol {
+                eprintln!(
+                    "warning: {abc}e = {got:.1} MHz in {name} differs from \
+                     {want:.1} MHz from the geometry by {diff:.2e}"
+                );
+            }
+        }
+    }
+}
+
+/// fit a semi-experimental equilibrium structure to the reference constants in
+/// `path` and print the fitted parameters and the residuals of each of
+/// `summaries`
+fn se_fit(summaries: &[Summary], names: &[String], path: &str) {
+    let fit = match semiexp::load_reference(path, summaries)
+        .and_then(|exp| semiexp::fit(summaries, &exp))
+    {
+        Ok(fit) => fit,
+        Err(e) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+    };
+    let parent = &summaries[0];
+    println!("Semi-experimental equilibrium structure (Å, degrees):");
+    println!("{:<20}{:>12}{:>12}{:>12}", "Coord", "Initial", "Fit", "σ");
+    for p in &fit.params {
+        let coord = &parent.curvils[p.curvils[0]];
+        println!(
+            "{:<20}{:12.6}{:12.6}{:12.6}",
+            format::curvil_desc(parent, coord),
+            p.initial,
+            p.value,
+            p.std_dev
+        );
+    }
+    if fit.converged {
+        println!(
+            "σ(fit) = {:.4}, converged in {} iterations",
+            fit.std_dev, fit.iterations
+        );
+    } else {
+        println!(
+            "σ(fit) = {:.4}, not converged after {} iterations",
+            fit.std_dev, fit.iterations
+        );
+        eprintln!("warning: the semi-experimental fit did not converge");
+    }
+    println!();
+    println!("Residuals Be(SE) - Be(fit) (MHz):");
+    for ((name, be), res) in names.iter().zip(&fit.be_se).zip(fit.residuals()) {
+        let be: Vec<_> = be.iter().map(|b| format!("{b:14.4}")).collect();
+        let res: Vec<_> = res.iter().map(|r| format!("{r:10.4}")).collect();
+        println!("{name:<20}{}{}", be.join(""), res.join(""));
+    }
+}
+
+/// print the rotational energy levels up to `jmax` of the ground state of each
+/// of `summaries` in representation `rep` and the transitions between them
+fn print_levels(
+    summaries: &[Summary],
+    names: &[String],
+    rep: Representation,
+    jmax: usize,
+) {
+    for (sum, name) in summaries.iter().zip(names) {
+        let Some(reduction) = format::preferred_reduction(sum) else {
+            eprintln!("skipping {name}: missing distortion constants");
+            continue;
+        };
+        let levels = match sum.rot_levels(0, reduction, rep, jmax) {
+            Ok(levels) => levels,
+            Err(e) => {
+                eprintln!("skipping {name}: {e}");
+                continue;
+            }
+        };
+        println!(
+            "Rotational energy levels of {name} ({reduction:?} reduction):"
+        );
+        println!("   J  Ka  Kc         E (MHz)");
+        for l in &levels {
+            println!("{:4}{:4}{:4}{:16.4}", l.j, l.ka, l.kc, l.energy);
+        }
+        println!();
+        println!("Transitions of {name}:");
+        println!("  J' Ka' Kc'    J\" Ka\" Kc\"         ν (MHz)  Type");
+        for t in rot_levels::transitions(&levels) {
+            let (u, l) = (t.upper, t.lower);
+            let kind = format!("{:?}", t.dipole).to_lowercase();
+            println!(
+                "{:4}{:4}{:4}{:6}{:4}{:4}{:16.4}{:>6}",
+                u.j, u.ka, u.kc, l.j, l.ka, l.kc, t.freq, kind
+            );
+        }
+        println!();
+    }
+}
+
+/// print the thermodynamic functions of each of `summaries` at each of `temps`
+fn print_thermo(
+    summaries: &[Summary],
+    names: &[String],
+    variant: Variant,
+    temps: &[f64],
+    sigma: Option<usize>,
+) {
+    for (sum, name) in summaries.iter().zip(names) {
+        let thermo = match sum.thermo(variant, temps, sigma) {
+            Ok(thermo) => thermo,
+            Err(e) => {
+                eprintln!("skipping {name}: {e}");
+                continue;
+            }
+        };
+        println!(
+            "Thermochemistry of {name} ({variant:?}, σ = {}, ZPE = {:.4} \
+             kJ/mol):",
+            thermo.sigma, thermo.zpe
+        );
+        println!(
+            "     T (K)                     q    H (kJ/mol)   S (J/mol K)  \
+             Cp (J/mol K)"
+        );
+        for row in &thermo.rows {
+            let mut total = row.total();
+            // report the total enthalpy relative to the bottom of the well
+            total.h += thermo.zpe;
+            let parts = [
+                ("trans", row.trans),
+                ("rot", row.rot),
+                ("vib", row.vib),
+                ("total", total),
+            ];
+            for (i, (label, c)) in parts.into_iter().enumerate() {
+                let temp = if i == 0 {
+                    format!("{:10.2}", row.temp)
+                } else {
+                    String::new()
+                };
+                println!(
+                    "{temp:>10}  {label:<6}{:14.4e}{:14.4}{:14.4}{:14.4}",
+                    c.q, c.h, c.s, c.cp
+                );
+            }
+        }
+        println!();
+    }
+}
+
+/// return a file name for the summary called `name`, replacing anything but
+/// letters and numbers with underscores
+fn file_stem(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// write `contents` to the file `stem`.`ext` in `dir`, reporting any errors
+fn write_file(dir: &str, stem: &str, ext: &str, contents: String) {
+    let path = Path::new(dir).join(format!("{stem}.{ext}"));
+    if let Err(e) = std::fs::write(&path, contents) {
+        eprintln!("failed to write {}: {e}", path.display());
+    }
+}
+
+/// write a PGOPHER file for each of `summaries` to the directory `dir`
+fn write_pgopher(summaries: &[Summary], names: &[String], dir: &str) {
+    for (summary, name) in summaries.iter().zip(names) {
+        let pgo = Pgopher { summary, name };
+        write_file(dir, &file_stem(name), "pgo", pgo.to_string());
+    }
+}
+
+/// write SPFIT/SPCAT files for each of `summaries` to the directory `dir`,
+/// either for just the ground state or for every state if `states` is true
+fn write_spcat(
+    summaries: &[Summary],
+    names: &[String],
+    dir: &str,
+    states: bool,
+) {
+    for (summary, name) in summaries.iter().zip(names) {
+        let stem = file_stem(name);
+        let nstates = if states { summary.rots.len().max(1) } else { 1 };
+        for state in 0..nstates {
+            let spcat = Spcat {
+                summary,
+                name,
+                state,
+            };
+            let stem = if states {
+                format!("{stem}_v{state}")
+            } else {
+                stem.clone()
+            };
+            let par = spcat.par();
+            write_file(dir, &stem, "par", par.clone());
+            write_file(dir, &stem, "var", par);
+            write_file(dir, &stem, "int", spcat.int());
+        }
+    }
+}
+
 fn load_plain<P>(p: P) -> Summary
 where
     P: AsRef<Path>,
@@ -224,18 +667,35 @@ fn main() {
         Recompute::No
     };
 
-    let mut summaries: Vec<_> = args
-        .infiles
-        .iter()
-        .map(|f| Summary::new(f, recompute))
-        .collect();
+    // report files that fail to parse instead of aborting, but keep track of
+    // them to drop the corresponding names
+    let mut summaries = Vec::new();
+    let mut failed = Vec::new();
+    for (i, f) in args.infiles.iter().enumerate() {
+        match Summary::try_new(f, recompute) {
+            Ok(s) => summaries.push(s),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed.push(i);
+            }
+        }
+    }
 
     if let Some(p) = args.plain {
         summaries.push(load_plain(p));
     }
 
+    if summaries.is_empty() {
+        exit(1);
+    }
+
     let names = if let Some(names) = args.names {
-        names.split(',').map(|s| s.trim().to_owned()).collect()
+        names
+            .split(',')
+            .enumerate()
+            .filter(|(i, _)| !failed.contains(i))
+            .map(|(_, s)| s.trim().to_owned())
+            .collect()
     } else {
         default_names(&summaries)
     };
@@ -259,6 +719,65 @@ fn main() {
         return;
     }
 
+    if let Some(tol) = args.check_rots {
+        check_rots(&summaries, &names, tol);
+    }
+
+    if let Some(path) = args.se_fit {
+        se_fit(&summaries, &names, &path);
+        return;
+    }
+
+    if !args.isotope.is_empty() {
+        for (summary, name) in summaries.iter_mut().zip(&names) {
+            match summary.isotopologue(&args.isotope) {
+                Ok(iso) => summary.isotopologue = Some(iso),
+                Err(e) => eprintln!("skipping isotopologue of {name}: {e}"),
+            }
+        }
+    }
+
+    if let Some(rep) = args.fill_reduction {
+        for summary in summaries.iter_mut() {
+            summary.fill_reduction(rep);
+        }
+    }
+
+    if let Some(jmax) = args.levels {
+        print_levels(&summaries, &names, args.rep.unwrap_or_default(), jmax);
+        return;
+    }
+
+    if let Some(variant) = args.thermo {
+        print_thermo(
+            &summaries,
+            &names,
+            variant,
+            &args.temps,
+            args.symmetry_number,
+        );
+        return;
+    }
+
+    if args.vpt2.is_some() || args.vpt2_cutoff.is_some() {
+        print_vpt2(
+            &summaries,
+            &names,
+            args.vpt2,
+            args.vpt2_cutoff,
+            args.resonance_window,
+        );
+        return;
+    }
+
+    if let Some(dir) = args.pgopher {
+        write_pgopher(&summaries, &names, &dir);
+    }
+
+    if let Some(dir) = args.spcat {
+        write_spcat(&summaries, &names, &dir, args.spcat_states);
+    }
+
     if args.wavenumbers {
         for summary in summaries.iter_mut() {
             for r in summary.rot_equil.iter_mut() {
@@ -269,13 +788,32 @@ fn main() {
                     *r /= TO_MHZ;
                 }
             }
+            for rot in summary.state_rots.data.values_mut() {
+                for r in rot.iter_mut() {
+                    *r /= TO_MHZ;
+                }
+            }
+            if let Some(iso) = summary.isotopologue.as_mut() {
+                for r in iso.rot_equil.iter_mut().chain(iso.rot0.iter_mut()) {
+                    *r /= TO_MHZ;
+                }
+            }
         }
     }
 
     if args.vib {
         just_vib(&summaries);
+    } else if let Some(thresh) = args.mixing {
+        mixed_states(&summaries, &names, thresh);
     } else if args.tex {
-        let summaries = format!("{}", Latex { summaries, names });
+        let summaries = format!(
+            "{}",
+            Latex {
+                summaries,
+                names,
+                extras: args.extra,
+            }
+        );
         let minus = regex::Regex::new(r"(\s+)-(\d)").unwrap();
         let summaries = minus.replace_all(&summaries, "$1$$-$$$2");
         println!(
@@ -294,11 +832,32 @@ fn main() {
     } else if args.json {
         println!("\n{}", serde_json::to_string_pretty(&summaries).unwrap());
     } else if args.csv {
-        println!("\n{}", Csv { summaries, names });
+        println!(
+            "\n{}",
+            Csv {
+                summaries,
+                names,
+                extras: args.extra,
+            }
+        );
     } else if args.org {
-        println!("\n{}", Org { summaries, names });
+        println!(
+            "\n{}",
+            Org {
+                summaries,
+                names,
+                extras: args.extra,
+            }
+        );
     } else {
-        println!("\n{}", Text { summaries, names });
+        println!(
+            "\n{}",
+            Text {
+                summaries,
+                names,
+                extras: args.extra,
+            }
+        );
     }
 }
 
@@ -1,13 +1,14 @@
 use summarize::{curvil::Curvil, Summary};
 
 use crate::{
-    format::{Format, TableType},
+    format::{Extra, Format, TableType},
     impl_display,
 };
 
 pub(crate) struct Org {
     pub summaries: Vec<Summary>,
     pub names: Vec<String>,
+    pub extras: Vec<Extra>,
 }
 
 impl_display!(Org);
@@ -37,6 +38,9 @@ impl Format for Org {
             TableType::Rot => String::from("#+name: rots"),
             TableType::DistA => String::from("#+name: dista"),
             TableType::DistS => String::from("#+name: dists"),
+            TableType::Nielsen => String::from("#+name: nielsen"),
+            TableType::Wilson => String::from("#+name: wilson"),
+            TableType::TauPrime => String::from("#+name: taup"),
             TableType::Curvil => {
                 format!("#+name: curvils{}", n + 1)
             }
@@ -52,6 +56,38 @@ impl Format for Org {
                     "Axes",
                 )
             }
+            TableType::Darling => {
+                format!(
+                    "#+name: darling{}
+|{:>16}|{:>10}|",
+                    n + 1,
+                    "Modes",
+                    "K",
+                )
+            }
+            TableType::Xmat => format!("#+name: xmat{}", n + 1),
+            TableType::Dist => format!("#+name: dist{}", n + 1),
+            TableType::Alpha => format!("#+name: alpha{}", n + 1),
+            TableType::Zeta => {
+                format!(
+                    "#+name: zeta{}
+|{:>16}|{:>4}|{:>10}|",
+                    n + 1,
+                    "Modes",
+                    "Axis",
+                    "Zeta",
+                )
+            }
+            TableType::Overtones => {
+                format!(
+                    "#+name: overtones{}
+|{:>16}|{:>10}|",
+                    n + 1,
+                    "State",
+                    "Energy",
+                )
+            }
+            TableType::Fc => String::from("#+name: fcs"),
         }
     }
 
@@ -70,21 +106,36 @@ impl Format for Org {
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            // pretty sure nothing else is printed in this part
-            Torsion(_, _, _, _) => todo!(),
+            Torsion(a, b, c, d) => format!(
+                "τ({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2} - {:>2}{d:<2})",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
             Linear(a, b, c) => format!(
                 "LIN({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2})",
                 sum.geom.atoms[*a - 1].label(),
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            Opb(_, _, _, _) => todo!(),
+            Opb(a, b, c, d) => format!(
+                "γ({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2} - {:>2}{d:<2})",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
         }
     }
 
     fn names(&self) -> &[String] {
         &self.names
     }
+
+    fn extras(&self) -> &[Extra] {
+        &self.extras
+    }
 }
 
 impl<'a> IntoIterator for &'a Org {
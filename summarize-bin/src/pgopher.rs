@@ -0,0 +1,216 @@
+//! writing PGOPHER input files for simulating rotational spectra
+
+use std::fmt::Display;
+
+use summarize::{reduction::Reduction, rotor::RotorType, Summary, TO_MHZ};
+
+use crate::format::preferred_reduction;
+
+/// a PGOPHER .pgo file describing the ground state and singly-excited
+/// fundamentals of a single [Summary]
+pub(crate) struct Pgopher<'a> {
+    pub summary: &'a Summary,
+    pub name: &'a str,
+}
+
+/// the PGOPHER names of the quartic and sextic distortion constants of the A-
+/// and S-reduced asymmetric top Hamiltonians and the symmetric top and linear
+/// Hamiltonians
+const ASYM_A: [&str; 12] = [
+    "DeltaJ", "DeltaK", "DeltaJK", "deltaJ", "deltaK", "PhiJ", "PhiK", "PhiJK",
+    "PhiKJ", "phiJ", "phiJK", "phiK",
+];
+const ASYM_S: [&str; 12] = [
+    "DJ", "DJK", "DK", "d1", "d2", "HJ", "HJK", "HKJ", "HK", "h1", "h2", "h3",
+];
+const SYM: [&str; 7] = ["DJ", "DJK", "DK", "HJ", "HJK", "HKJ", "HK"];
+const LIN: [&str; 2] = ["D", "H"];
+
+impl Pgopher<'_> {
+    fn reduction(&self) -> Option<Reduction> {
+        preferred_reduction(self.summary)
+    }
+
+    /// return the PGOPHER names and values in MHz of the distortion constants
+    /// for the type of rotor and reduction of the summary
+    fn dist_consts(&self) -> Vec<(&'static str, Option<f64>)> {
+        let (d, p) = (&self.summary.deltas, &self.summary.phis);
+        let (names, vals) = match (self.summary.rotor, self.reduction()) {
+            (RotorType::Linear, _) => (&LIN[..], vec![d.de, p.he]),
+            (_, None) => return Vec::new(),
+            (RotorType::Asymmetric, Some(Reduction::A)) => (
+                &ASYM_A[..],
+                vec![
+                    d.big_delta_j,
+                    d.big_delta_k,
+                    d.big_delta_jk,
+                    d.delta_j,
+                    d.delta_k,
+                    p.big_phi_j,
+                    p.big_phi_k,
+                    p.big_phi_jk,
+                    p.big_phi_kj,
+                    p.phi_j,
+                    p.phi_jk,
+                    p.phi_k,
+                ],
+            ),
+            (RotorType::Asymmetric, Some(Reduction::S)) => (
+                &ASYM_S[..],
+                vec![
+                    d.d_j, d.d_jk, d.d_k, d.d1, d.d2, p.h_j, p.h_jk, p.h_kj,
+                    p.h_k, p.h1, p.h2, p.h3,
+                ],
+            ),
+            // the off-diagonal constants vanish for symmetric tops, where
+            // the two reductions coincide
+            (_, Some(Reduction::A)) => (
+                &SYM[..],
+                vec![
+                    d.big_delta_j,
+                    d.big_delta_jk,
+                    d.big_delta_k,
+                    p.big_phi_j,
+                    p.big_phi_jk,
+                    p.big_phi_kj,
+                    p.big_phi_k,
+                ],
+            ),
+            (_, Some(Reduction::S)) => (
+                &SYM[..],
+                vec![d.d_j, d.d_jk, d.d_k, p.h_j, p.h_jk, p.h_kj, p.h_k],
+            ),
+        };
+        names.iter().copied().zip(vals).collect()
+    }
+
+    /// return the PGOPHER names and values in MHz of the rotational constants
+    /// in `rots`, which contains the unique constants for the type of rotor or
+    /// all three constants of a split state
+    fn rot_consts(&self, rots: &[f64]) -> Vec<(&'static str, f64)> {
+        // a SymmetricTop has no way to represent a split state, so average it
+        // back to the unique constants
+        let rots = &self.summary.rotor.unique_rots(rots);
+        let names: &[&str] = match self.summary.rotor {
+            RotorType::Linear => &["B"],
+            RotorType::Prolate => &["A", "B"],
+            RotorType::Oblate => &["B", "C"],
+            // spherical tops are written as oblate symmetric tops, so C has
+            // to be set to B too instead of keeping PGOPHER's default
+            RotorType::Spherical => {
+                return rots
+                    .first()
+                    .map_or(Vec::new(), |&b| vec![("B", b), ("C", b)])
+            }
+            RotorType::Asymmetric => &["A", "B", "C"],
+        };
+        names.iter().copied().zip(rots.iter().copied()).collect()
+    }
+
+    /// write the state `name` with rotational constants `rots` and band
+    /// origin `origin` in cm⁻¹
+    fn write_state(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        kind: &str,
+        name: &str,
+        origin: Option<f64>,
+        rots: &[f64],
+    ) -> std::fmt::Result {
+        writeln!(f, r#"        <{kind} Name="{name}">"#)?;
+        if let Some(origin) = origin {
+            write_param(f, "Origin", origin)?;
+        }
+        for (name, v) in self.rot_consts(rots) {
+            write_param(f, name, v / TO_MHZ)?;
+        }
+        // SPECTRO only reports distortion constants for the ground state, so
+        // carry them over to the excited states too
+        for (name, v) in self.dist_consts() {
+            if let Some(v) = v {
+                write_param(f, name, v / TO_MHZ)?;
+            }
+        }
+        writeln!(f, "        </{kind}>")
+    }
+}
+
+/// write a single PGOPHER parameter. PGOPHER stores every energy in its files
+/// in cm⁻¹, whatever units it displays them in
+fn write_param(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    v: f64,
+) -> std::fmt::Result {
+    writeln!(f, r#"          <Parameter Name="{name}" Value="{v:e}"/>"#)
+}
+
+/// escape the characters in `s` that are not allowed in an XML attribute
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl Display for Pgopher<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sum = self.summary;
+        let (molecule, manifold, top) = match sum.rotor {
+            RotorType::Linear => ("LinearMolecule", "LinearManifold", "Linear"),
+            RotorType::Prolate | RotorType::Oblate | RotorType::Spherical => {
+                ("SymmetricMolecule", "SymmetricManifold", "SymmetricTop")
+            }
+            RotorType::Asymmetric => {
+                ("AsymmetricMolecule", "AsymmetricManifold", "AsymmetricTop")
+            }
+        };
+        let name = escape(self.name);
+        writeln!(f, r#"<?xml version="1.0"?>"#)?;
+        writeln!(f, r#"<Mixture Units="MHz">"#)?;
+        writeln!(f, r#"  <Species Name="{name}">"#)?;
+        writeln!(f, r#"    <{molecule} Name="{name}">"#)?;
+        match sum.rotor {
+            RotorType::Asymmetric => {
+                let watson = match self.reduction() {
+                    Some(Reduction::S) => "SReduction",
+                    _ => "AReduction",
+                };
+                writeln!(
+                    f,
+                    r#"      <Parameter Name="Watson" Value="{watson}"/>"#
+                )?;
+            }
+            RotorType::Prolate => {
+                writeln!(
+                    f,
+                    r#"      <Parameter Name="Prolate" Value="True"/>"#
+                )?;
+            }
+            RotorType::Oblate | RotorType::Spherical => {
+                writeln!(
+                    f,
+                    r#"      <Parameter Name="Prolate" Value="False"/>"#
+                )?;
+            }
+            RotorType::Linear => {}
+        }
+
+        writeln!(f, r#"      <{manifold} Name="Ground" Initial="True">"#)?;
+        if let Some(rots) = sum.rots.first() {
+            self.write_state(f, top, "v=0", None, rots)?;
+        }
+        writeln!(f, "      </{manifold}>")?;
+
+        writeln!(f, r#"      <{manifold} Name="Excited">"#)?;
+        for (i, rots) in sum.rots.iter().enumerate().skip(1) {
+            let origin = sum.corr.get(i - 1).copied();
+            self.write_state(f, top, &format!("v{i}=1"), origin, rots)?;
+        }
+        writeln!(f, "      </{manifold}>")?;
+
+        writeln!(f, "    </{molecule}>")?;
+        writeln!(f, "  </Species>")?;
+        writeln!(f, "</Mixture>")
+    }
+}
@@ -0,0 +1,234 @@
+//! writing input files for Pickett's SPFIT and SPCAT programs
+
+use std::fmt::Write;
+
+use summarize::{reduction::Reduction, rotor::RotorType, Summary};
+
+use crate::format::preferred_reduction;
+
+/// Boltzmann's constant divided by Planck's constant in MHz/K
+const K_OVER_H: f64 = 20836.61912;
+
+/// temperature in K for the intensities in the .int file
+const TEMP: f64 = 300.0;
+
+/// largest J to include in the predicted catalog
+const JMAX: usize = 40;
+
+/// largest frequency to include in the predicted catalog in GHz
+const FQLIM: f64 = 1000.0;
+
+/// parameter uncertainty that marks a parameter as floated in SPFIT
+const FLOAT: f64 = 1e37;
+
+/// parameter uncertainty that marks a parameter as fixed in SPFIT
+const FIXED: f64 = 1e-37;
+
+/// Pickett's ID codes for the sextic distortion constants
+const SEXTIC: [usize; 9] =
+    [300, 1200, 2100, 3000, 40200, 41100, 42000, 50100, 60000];
+
+/// SPFIT/SPCAT input files for the rotational and distortion constants of a
+/// single vibrational state of a [Summary]
+pub(crate) struct Spcat<'a> {
+    pub summary: &'a Summary,
+    pub name: &'a str,
+
+    /// the index into [Summary::rots] of the state to write
+    pub state: usize,
+}
+
+/// a single line of a .par file: Pickett's ID code for the parameter, its
+/// value in MHz, and a label
+type Param = (usize, f64, &'static str);
+
+impl Spcat<'_> {
+    /// return the rotational constants of the state, falling back on the
+    /// equilibrium constants for the ground state if there are no
+    /// vibrationally-averaged constants
+    fn rots(&self) -> &[f64] {
+        match self.summary.rots.get(self.state) {
+            Some(rots) => rots.as_slice(),
+            None if self.state == 0 => self.summary.rot_equil.as_slice(),
+            None => &[],
+        }
+    }
+
+    /// return the parameter lines of the .par file. the coefficients of the
+    /// quartic operators in Pickett's Hamiltonian are the negatives of the
+    /// usual ΔJ, ΔJK, and ΔK (or DJ, DJK, and DK), and δJ and δK in the A
+    /// reduction
+    fn params(&self) -> Vec<Param> {
+        let sum = self.summary;
+        let (d, p) = (&sum.deltas, &sum.phis);
+        let rots = self.rots();
+        let mut ret = Vec::new();
+        match (sum.rotor, rots) {
+            (RotorType::Linear | RotorType::Spherical, [b, ..]) => {
+                ret.push((100, *b, "B"));
+            }
+            (RotorType::Prolate, [a, b, ..]) => {
+                ret.push((100, *b, "B"));
+                ret.push((1000, a - b, "A-B"));
+            }
+            (RotorType::Oblate, [b, c, ..]) => {
+                ret.push((100, *b, "B"));
+                ret.push((1000, c - b, "C-B"));
+            }
+            (RotorType::Asymmetric, [a, b, c, ..]) => {
+                ret.push((10000, *a, "A"));
+                ret.push((20000, *b, "B"));
+                ret.push((30000, *c, "C"));
+            }
+            _ => {}
+        }
+        let dist: Vec<(usize, Option<f64>, &str)> =
+            match (sum.rotor, preferred_reduction(sum)) {
+                (RotorType::Linear, _) => {
+                    vec![(200, d.de.map(|v| -v), "-D"), (300, p.he, "H")]
+                }
+                (_, None) => Vec::new(),
+                (RotorType::Asymmetric, Some(Reduction::A)) => vec![
+                    (200, d.big_delta_j.map(|v| -v), "-DeltaJ"),
+                    (1100, d.big_delta_jk.map(|v| -v), "-DeltaJK"),
+                    (2000, d.big_delta_k.map(|v| -v), "-DeltaK"),
+                    (40100, d.delta_j.map(|v| -v), "-deltaJ"),
+                    (41000, d.delta_k.map(|v| -v), "-deltaK"),
+                    (300, p.big_phi_j, "PhiJ"),
+                    (1200, p.big_phi_jk, "PhiJK"),
+                    (2100, p.big_phi_kj, "PhiKJ"),
+                    (3000, p.big_phi_k, "PhiK"),
+                    (40200, p.phi_j, "phiJ"),
+                    (41100, p.phi_jk, "phiJK"),
+                    (42000, p.phi_k, "phiK"),
+                ],
+                (RotorType::Asymmetric, Some(Reduction::S)) => vec![
+                    (200, d.d_j.map(|v| -v), "-DJ"),
+                    (1100, d.d_jk.map(|v| -v), "-DJK"),
+                    (2000, d.d_k.map(|v| -v), "-DK"),
+                    (40100, d.d1, "d1"),
+                    (50000, d.d2, "d2"),
+                    (300, p.h_j, "HJ"),
+                    (1200, p.h_jk, "HJK"),
+                    (2100, p.h_kj, "HKJ"),
+                    (3000, p.h_k, "HK"),
+                    (40200, p.h1, "h1"),
+                    (50100, p.h2, "h2"),
+                    (60000, p.h3, "h3"),
+                ],
+                // the two reductions coincide for symmetric tops
+                (_, Some(Reduction::A)) => vec![
+                    (200, d.big_delta_j.map(|v| -v), "-DJ"),
+                    (1100, d.big_delta_jk.map(|v| -v), "-DJK"),
+                    (2000, d.big_delta_k.map(|v| -v), "-DK"),
+                    (300, p.big_phi_j, "HJ"),
+                    (1200, p.big_phi_jk, "HJK"),
+                    (2100, p.big_phi_kj, "HKJ"),
+                    (3000, p.big_phi_k, "HK"),
+                ],
+                (_, Some(Reduction::S)) => vec![
+                    (200, d.d_j.map(|v| -v), "-DJ"),
+                    (1100, d.d_jk.map(|v| -v), "-DJK"),
+                    (2000, d.d_k.map(|v| -v), "-DK"),
+                    (300, p.h_j, "HJ"),
+                    (1200, p.h_jk, "HJK"),
+                    (2100, p.h_kj, "HKJ"),
+                    (3000, p.h_k, "HK"),
+                ],
+            };
+        ret.extend(
+            dist.into_iter()
+                .filter_map(|(id, v, label)| v.map(|v| (id, v, label))),
+        );
+        ret
+    }
+
+    /// return the contents of the .par file, which SPCAT also reads as the
+    /// .var file. the rotational and quartic constants are floated and the
+    /// sextic constants are fixed
+    pub(crate) fn par(&self) -> String {
+        let params = self.params();
+        let chr = match preferred_reduction(self.summary) {
+            Some(Reduction::S) => 's',
+            _ => 'a',
+        };
+        let (knmax, iax) = match self.summary.rotor {
+            RotorType::Linear => (0, 1),
+            RotorType::Oblate | RotorType::Spherical => (99, 3),
+            RotorType::Prolate | RotorType::Asymmetric => (99, 1),
+        };
+        let mut s = String::new();
+        writeln!(s, "{} v={}", self.name, self.state).unwrap();
+        writeln!(
+            s,
+            "{:4} 1000   10    0  {}  {}  {} 1.0000000000",
+            params.len(),
+            fortran(0.0, 4),
+            fortran(1e6, 4),
+            fortran(1.0, 4),
+        )
+        .unwrap();
+        writeln!(
+            s,
+            "{chr}    1    1    0 {knmax:4}    0 {iax:4}    1    1    0"
+        )
+        .unwrap();
+        for (id, v, label) in params {
+            let err = if SEXTIC.contains(&id) { FIXED } else { FLOAT };
+            writeln!(
+                s,
+                "{id:13} {:>23} {:>12} /{label}",
+                fortran(v, 15),
+                fortran(err, 1)
+            )
+            .unwrap();
+        }
+        s
+    }
+
+    /// return the contents of the .int file, with unit dipole moments along
+    /// each of the allowed axes and the classical rotational partition
+    /// function at [TEMP]
+    pub(crate) fn int(&self) -> String {
+        let axes: &[usize] = match self.summary.rotor {
+            RotorType::Asymmetric => &[1, 2, 3],
+            _ => &[1],
+        };
+        let mut s = String::new();
+        writeln!(s, "{} v={}", self.name, self.state).unwrap();
+        writeln!(
+            s,
+            "0000  99999 {:.4}    0 {JMAX:4} -10.0 -10.0 {FQLIM:8.1} {TEMP:6.1}",
+            self.partition(),
+        )
+        .unwrap();
+        for axis in axes {
+            writeln!(s, "{axis:4}  1.000").unwrap();
+        }
+        s
+    }
+
+    /// return the classical rotational partition function at [TEMP],
+    /// ignoring the symmetry number
+    fn partition(&self) -> f64 {
+        let kt = K_OVER_H * TEMP;
+        match self.summary.rotor.expand(self.rots()) {
+            [_, Some(b), _] if self.summary.rotor == RotorType::Linear => {
+                kt / b
+            }
+            [Some(a), Some(b), Some(c)] => {
+                (std::f64::consts::PI / (a * b * c)).sqrt() * kt.powf(1.5)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+/// format `v` in Fortran's E format with `prec` digits after the decimal point
+/// and a signed, three-digit exponent, like `1.2345E+004`
+fn fortran(v: f64, prec: usize) -> String {
+    let s = format!("{v:.prec$e}");
+    let (mant, exp) = s.split_once('e').unwrap();
+    let exp: i32 = exp.parse().unwrap();
+    format!("{mant}E{exp:+04}")
+}
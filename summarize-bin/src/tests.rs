@@ -1,7 +1,20 @@
-use std::{fmt::Write, fs::read_to_string};
-use summarize::{Recompute, Summary};
+use std::{
+    fmt::{Display, Write},
+    fs::read_to_string,
+};
+use summarize::{rotor::RotorType, Recompute, Summary, TO_MHZ};
 
-use crate::{default_names, latex::Latex, text::Text};
+use crate::{
+    csv::Csv,
+    default_names,
+    format::{Extra, Format},
+    latex::Latex,
+    org::Org,
+    pgopher::Pgopher,
+    spcat::Spcat,
+    text::Text,
+    Args,
+};
 
 #[test]
 fn text() {
@@ -16,7 +29,16 @@ fn text() {
         .collect();
     let mut got = String::new();
     let names = default_names(&summaries);
-    write!(got, "{}", Text { summaries, names }).unwrap();
+    write!(
+        got,
+        "{}",
+        Text {
+            summaries,
+            names,
+            extras: Vec::new(),
+        }
+    )
+    .unwrap();
 
     let want = read_to_string("testfiles/want.txt").unwrap();
 
@@ -41,7 +63,16 @@ fn latex() {
         .collect();
     let mut got = String::new();
     let names = default_names(&summaries);
-    write!(got, "{}", Latex { summaries, names }).unwrap();
+    write!(
+        got,
+        "{}",
+        Latex {
+            summaries,
+            names,
+            extras: Vec::new(),
+        }
+    )
+    .unwrap();
 
     let want = read_to_string("testfiles/want.tex").unwrap();
 
@@ -52,3 +83,301 @@ fn latex() {
         panic!(r#" (diff "/tmp/got" "summarize-bin/testfiles/want.tex") "#);
     }
 }
+
+/// wrapper for printing only the curvilinear coordinate table
+struct Curvils<T>(T);
+
+impl<T> Display for Curvils<T>
+where
+    T: Format,
+    for<'a> &'a T: IntoIterator<Item = &'a Summary>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.print_curvils(f)
+    }
+}
+
+/// load c2h4.out for testing the curvilinear coordinate labels. SPECTRO does
+/// not print equilibrium or vibrationally averaged values for the torsions,
+/// so their rows should have blank cells
+fn c2h4_curvils() -> (Vec<Summary>, Vec<String>) {
+    let summaries = vec![Summary::new("../testfiles/c2h4.out", Recompute::No)];
+    let names = default_names(&summaries);
+    (summaries, names)
+}
+
+/// compare `got` to the contents of `want`, writing `got` to /tmp/got on
+/// failure
+fn check_golden(got: String, want: &str) {
+    if got != read_to_string(want).unwrap() {
+        use std::io::Write;
+        let mut f = std::fs::File::create("/tmp/got").unwrap();
+        write!(f, "{got}").unwrap();
+        panic!(r#" (diff "/tmp/got" "summarize-bin/{want}") "#);
+    }
+}
+
+#[test]
+fn text_curvils() {
+    let (summaries, names) = c2h4_curvils();
+    let got = Curvils(Text {
+        summaries,
+        names,
+        extras: Vec::new(),
+    })
+    .to_string();
+    check_golden(got, "testfiles/c2h4_curvils.txt");
+}
+
+#[test]
+fn csv_curvils() {
+    let (summaries, names) = c2h4_curvils();
+    let got = Curvils(Csv {
+        summaries,
+        names,
+        extras: Vec::new(),
+    })
+    .to_string();
+    check_golden(got, "testfiles/c2h4_curvils.csv");
+}
+
+#[test]
+fn org_curvils() {
+    let (summaries, names) = c2h4_curvils();
+    let got = Curvils(Org {
+        summaries,
+        names,
+        extras: Vec::new(),
+    })
+    .to_string();
+    check_golden(got, "testfiles/c2h4_curvils.org");
+}
+
+#[test]
+fn latex_curvils() {
+    let (summaries, names) = c2h4_curvils();
+    let got = Curvils(Latex {
+        summaries,
+        names,
+        extras: Vec::new(),
+    })
+    .to_string();
+    check_golden(got, "testfiles/c2h4_curvils.tex");
+}
+
+#[test]
+fn ped_desc() {
+    let summaries = vec![
+        Summary::new("../testfiles/spectro.out", Recompute::No),
+        Summary::new("../testfiles/c2h4.out", Recompute::No),
+    ];
+    let names = default_names(&summaries);
+    let text = Text {
+        summaries,
+        names,
+        extras: vec![Extra::Ped],
+    };
+    let [c3h2, c2h4] = &text.summaries[..] else {
+        unreachable!()
+    };
+    assert_eq!(text.ped_desc(c3h2, 0), "49% C2-H4 str + 49% C3-H5 str");
+    // the rows of the C2H4 PED are not the curvilinear coordinates, and the
+    // raw elements would give 140% for coordinate 1 in mode 9
+    assert_eq!(
+        text.ped_desc(c2h4, 0),
+        "36% coord. 3 + 24% coord. 5 + 18% coord. 1"
+    );
+    assert_eq!(
+        text.ped_desc(c2h4, 8),
+        "43% coord. 1 + 25% coord. 6 + 21% coord. 4"
+    );
+}
+
+/// SPECTRO's mean square amplitude matrices for the redundant coordinates of
+/// C2H4 have negative diagonal elements, so the RMS cells should be blank
+/// rather than NaN
+#[test]
+fn text_amplitudes() {
+    let (summaries, names) = c2h4_curvils();
+    let got = Curvils(Text {
+        summaries,
+        names,
+        extras: vec![Extra::Amplitudes],
+    })
+    .to_string();
+    assert!(!got.contains("NaN"));
+    check_golden(got, "testfiles/c2h4_amplitudes.txt");
+}
+
+/// the inertia defect row should only be printed when there is an asymmetric
+/// top to fill it
+#[test]
+fn text_inertia_defect() {
+    let summaries = vec![Summary::new("../testfiles/nh3.out", Recompute::No)];
+    let names = default_names(&summaries);
+    let got = Text {
+        summaries,
+        names,
+        extras: Vec::new(),
+    }
+    .to_string();
+    assert!(!got.contains("amu Å²"));
+}
+
+/// assert that `got` contains a PGOPHER parameter `name` with the value `mhz`
+/// converted to cm⁻¹
+fn check_param(got: &str, name: &str, mhz: f64) {
+    let want =
+        format!(r#"<Parameter Name="{name}" Value="{:e}"/>"#, mhz / TO_MHZ);
+    assert!(got.contains(&want), "{want} not found in\n{got}");
+}
+
+#[test]
+fn pgopher() {
+    // asymmetric top with both reductions
+    let summary = Summary::new("../testfiles/spectro.out", Recompute::No);
+    let got = Pgopher {
+        summary: &summary,
+        name: "c3h2",
+    }
+    .to_string();
+    assert!(got.contains(r#"<AsymmetricMolecule Name="c3h2">"#));
+    assert!(got.contains(r#"<Parameter Name="Watson" Value="AReduction"/>"#));
+    check_param(&got, "A", summary.rots[0][0]);
+    check_param(&got, "C", summary.rots[0][2]);
+    check_param(&got, "DeltaJK", summary.deltas.big_delta_jk.unwrap());
+    check_param(&got, "phiK", summary.phis.phi_k.unwrap());
+    check_param(&got, "B", summary.rots[9][1]);
+    assert!(!got.contains(r#"Name="d1""#));
+    assert_eq!(got.matches("<AsymmetricTop ").count(), 10);
+    assert!(got.contains(r#"<AsymmetricTop Name="v9=1">"#));
+    assert!(got.contains(&format!(
+        r#"<Parameter Name="Origin" Value="{:e}"/>"#,
+        summary.corr[8]
+    )));
+
+    // oblate symmetric top with only the S reduction
+    let summary = Summary::new("../testfiles/nh3.out", Recompute::No);
+    let got = Pgopher {
+        summary: &summary,
+        name: "nh3",
+    }
+    .to_string();
+    assert!(got.contains(r#"<SymmetricMolecule Name="nh3">"#));
+    assert!(got.contains(r#"<Parameter Name="Prolate" Value="False"/>"#));
+    check_param(&got, "B", summary.rots[0][0]);
+    check_param(&got, "C", summary.rots[0][1]);
+    check_param(&got, "DJK", summary.deltas.d_jk.unwrap());
+    check_param(&got, "HKJ", summary.phis.h_kj.unwrap());
+    assert!(!got.contains(r#"Name="A""#));
+    // one excited state per fundamental, without the overtones
+    assert_eq!(got.matches("<SymmetricTop ").count(), 5);
+    assert!(got.contains(&format!(
+        r#"<Parameter Name="Origin" Value="{:e}"/>"#,
+        summary.corr[3]
+    )));
+
+    // spherical tops are oblate symmetric tops with C = B
+    let mut summary = summary;
+    summary.rotor = RotorType::Spherical;
+    summary.rots = vec![vec![summary.rots[0][0]]];
+    let got = Pgopher {
+        summary: &summary,
+        name: "sph",
+    }
+    .to_string();
+    assert!(got.contains(r#"<Parameter Name="Prolate" Value="False"/>"#));
+    check_param(&got, "B", summary.rots[0][0]);
+    check_param(&got, "C", summary.rots[0][0]);
+
+    // linear molecule
+    let summary = Summary::new("../testfiles/degmode.out", Recompute::No);
+    let got = Pgopher {
+        summary: &summary,
+        name: "a&b",
+    }
+    .to_string();
+    assert!(got.contains(r#"<LinearMolecule Name="a&amp;b">"#));
+    check_param(&got, "B", summary.rots[0][0]);
+    check_param(&got, "D", summary.deltas.de.unwrap());
+}
+
+/// parse the parameter lines of a .par file into pairs of ID and value
+fn read_par(par: &str) -> Vec<(usize, f64)> {
+    par.lines()
+        .skip(3)
+        .map(|line| {
+            let fields: Vec<_> = line.split_ascii_whitespace().collect();
+            (fields[0].parse().unwrap(), fields[1].parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn spcat() {
+    let summary = Summary::new("../testfiles/spectro.out", Recompute::No);
+    let spcat = Spcat {
+        summary: &summary,
+        name: "c3h2",
+        state: 0,
+    };
+    let par = spcat.par();
+    check_golden(par.clone(), "testfiles/c3h2.par");
+    check_golden(spcat.int(), "testfiles/c3h2.int");
+
+    // reading the values back recovers the constants
+    let got = read_par(&par);
+    let d = &summary.deltas;
+    assert_eq!(got[0], (10000, summary.rots[0][0]));
+    assert_eq!(got[2], (30000, summary.rots[0][2]));
+    assert_eq!(got[3], (200, -d.big_delta_j.unwrap()));
+    assert_eq!(got[7], (41000, -d.delta_k.unwrap()));
+    assert_eq!(got[14], (42000, summary.phis.phi_k.unwrap()));
+
+    // excited state of an oblate top with only the S reduction
+    let summary = Summary::new("../testfiles/nh3.out", Recompute::No);
+    let par = Spcat {
+        summary: &summary,
+        name: "nh3",
+        state: 1,
+    }
+    .par();
+    check_golden(par.clone(), "testfiles/nh3_v1.par");
+    let got = read_par(&par);
+    let rots = &summary.rots[1];
+    assert_eq!(got[0], (100, rots[0]));
+    assert_eq!(got[1], (1000, rots[1] - rots[0]));
+    assert_eq!(got[2], (200, -summary.deltas.d_j.unwrap()));
+}
+
+#[test]
+fn vpt2_args() {
+    use clap::Parser;
+
+    // either limit is enough on its own to print the VPT2 levels
+    let args =
+        Args::try_parse_from(["summarize", "--vpt2-cutoff", "3000", "a.out"])
+            .unwrap();
+    assert_eq!((args.vpt2, args.vpt2_cutoff), (None, Some(3000.0)));
+    let args =
+        Args::try_parse_from(["summarize", "--vpt2", "3", "a.out"]).unwrap();
+    assert_eq!((args.vpt2, args.vpt2_cutoff), (Some(3), None));
+
+    // but the resonance window needs one of them
+    assert!(Args::try_parse_from([
+        "summarize",
+        "--vpt2-cutoff",
+        "3000",
+        "--resonance-window",
+        "10",
+        "a.out"
+    ])
+    .is_ok());
+    assert!(Args::try_parse_from([
+        "summarize",
+        "--resonance-window",
+        "10",
+        "a.out"
+    ])
+    .is_err());
+}
@@ -1,10 +1,14 @@
 use summarize::{curvil::Curvil, Summary};
 
-use crate::{format::Format, impl_display};
+use crate::{
+    format::{Extra, Format},
+    impl_display,
+};
 
 pub(crate) struct Text {
     pub summaries: Vec<Summary>,
     pub names: Vec<String>,
+    pub extras: Vec<Extra>,
 }
 
 impl_display!(Text);
@@ -39,21 +43,36 @@ impl Format for Text {
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            // pretty sure nothing else is printed in this part
-            Torsion(_, _, _, _) => todo!(),
+            Torsion(a, b, c, d) => format!(
+                "τ({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2} - {:>2}{d:<2})",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
             Linear(a, b, c) => format!(
                 "LIN({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2})",
                 sum.geom.atoms[*a - 1].label(),
                 sum.geom.atoms[*b - 1].label(),
                 sum.geom.atoms[*c - 1].label()
             ),
-            Opb(_, _, _, _) => todo!(),
+            Opb(a, b, c, d) => format!(
+                "γ({:>2}{a:<2} - {:>2}{b:<2} - {:>2}{c:<2} - {:>2}{d:<2})",
+                sum.geom.atoms[*a - 1].label(),
+                sum.geom.atoms[*b - 1].label(),
+                sum.geom.atoms[*c - 1].label(),
+                sum.geom.atoms[*d - 1].label()
+            ),
         }
     }
 
     fn names(&self) -> &[String] {
         &self.names
     }
+
+    fn extras(&self) -> &[Extra] {
+        &self.extras
+    }
 }
 
 impl<'a> IntoIterator for &'a Text {